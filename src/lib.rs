@@ -4,11 +4,260 @@
 //!   error value is discarded.
 //! - [`Resultish::strict`]ly, where [`Both`] is mapped to [`Result::Err`], and the
 //!   success value is discarded.
+//!
+//! # Features
+//!
+//! - `std` (enabled by default): Enables functionality that depends on the standard library, such
+//!   as `Resultish::as_error`. Without this feature, the crate is `no_std`.
+//! - `alloc` (enabled by `std`): Enables functionality that depends on the `alloc` crate, such as
+//!   collecting an iterator of [`Resultish`] into a `Resultish<C, Vec<E>>`.
+//! - `serde`: Implements `Serialize` and `Deserialize` for [`Resultish`], using serde's default
+//!   externally tagged enum representation, where [`Both`] is represented as a two-element
+//!   sequence.
+//!
+//!   ```
+//!   # #[cfg(feature = "serde")]
+//!   # {
+//!   use resultish::Resultish::{self, Both, Err, Ok};
+//!
+//!   let x: Resultish<i32, String> = Ok(3);
+//!   let json = serde_json::to_string(&x).unwrap();
+//!   assert_eq!(json, r#"{"Ok":3}"#);
+//!   assert_eq!(serde_json::from_str::<Resultish<i32, String>>(&json).unwrap(), x);
+//!
+//!   let x: Resultish<i32, String> = Err("Some error message".to_string());
+//!   let json = serde_json::to_string(&x).unwrap();
+//!   assert_eq!(json, r#"{"Err":"Some error message"}"#);
+//!   assert_eq!(serde_json::from_str::<Resultish<i32, String>>(&json).unwrap(), x);
+//!
+//!   let x: Resultish<i32, String> = Both(3, "Some error message".to_string());
+//!   let json = serde_json::to_string(&x).unwrap();
+//!   assert_eq!(json, r#"{"Both":[3,"Some error message"]}"#);
+//!   assert_eq!(serde_json::from_str::<Resultish<i32, String>>(&json).unwrap(), x);
+//!   # }
+//!   ```
+//! - `arbitrary`: Implements `arbitrary::Arbitrary` for [`Resultish`], for fuzzing code that
+//!   consumes a [`Resultish`].
+//!
+//!   ```
+//!   # #[cfg(feature = "arbitrary")]
+//!   # {
+//!   use arbitrary::{Arbitrary, Unstructured};
+//!   use resultish::Resultish::{self, Both, Err, Ok};
+//!
+//!   let mut u = Unstructured::new(&[0, 3, 1, 4, 2, 5, 6]);
+//!   assert_eq!(Resultish::<u8, u8>::arbitrary(&mut u).unwrap(), Ok(3));
+//!   assert_eq!(Resultish::<u8, u8>::arbitrary(&mut u).unwrap(), Err(4));
+//!   assert_eq!(Resultish::<u8, u8>::arbitrary(&mut u).unwrap(), Both(5, 6));
+//!   # }
+//!   ```
+//! - `quickcheck`: Implements `quickcheck::Arbitrary` for [`Resultish`], for property-based
+//!   testing.
+//!
+//!   ```
+//!   # #[cfg(feature = "quickcheck")]
+//!   # {
+//!   use quickcheck::{Arbitrary, Gen};
+//!   use resultish::Resultish;
+//!
+//!   let mut g = Gen::new(10);
+//!   let x: Vec<Resultish<u8, u8>> = (0..100).map(|_| Resultish::arbitrary(&mut g)).collect();
+//!   assert!(x.iter().any(|x| matches!(x, Resultish::Ok(_))));
+//!   assert!(x.iter().any(|x| matches!(x, Resultish::Err(_))));
+//!   assert!(x.iter().any(|x| matches!(x, Resultish::Both(_, _))));
+//!   # }
+//!   ```
+//! - `either`: Enables conversions between [`Resultish`] and `either::Either`.
+//!   `Left` is converted to [`Ok`], and `Right` to [`Err`], so `Both` is never produced. In the
+//!   other direction, [`Both`] is resolved with
+#![cfg_attr(
+    feature = "either",
+    doc = "  [`Resultish::to_either_lenient`] or [`Resultish::to_either_strict`]."
+)]
+#![cfg_attr(
+    not(feature = "either"),
+    doc = "  `Resultish::to_either_lenient` or `Resultish::to_either_strict`."
+)]
+//!
+//!   ```
+//!   # #[cfg(feature = "either")]
+//!   # {
+//!   use either::Either::{Left, Right};
+//!   use resultish::Resultish::{self, Both, Err, Ok};
+//!
+//!   let x: Resultish<i32, &str> = Resultish::from(Left(3));
+//!   assert_eq!(x, Ok(3));
+//!   assert_eq!(Resultish::from(x.to_either_lenient()), x);
+//!   assert_eq!(Resultish::from(x.to_either_strict()), x);
+//!
+//!   let x: Resultish<i32, &str> = Resultish::from(Right("Some error message"));
+//!   assert_eq!(x, Err("Some error message"));
+//!   assert_eq!(Resultish::from(x.to_either_lenient()), x);
+//!   assert_eq!(Resultish::from(x.to_either_strict()), x);
+//!
+//!   let x: Resultish<i32, &str> = Both(3, "Some error message");
+//!   assert_eq!(x.to_either_lenient(), Left(3));
+//!   assert_eq!(x.to_either_strict(), Right("Some error message"));
+//!   # }
+//!   ```
+//! - `anyhow` (enables `std`): Enables conversions from [`Resultish`] to `anyhow::Result`, with
+//!   `Resultish::into_anyhow_lenient` and `Resultish::into_anyhow_strict`. Context can be
+//!   attached to the error value beforehand with `Resultish::with_context`.
+//!
+//!   ```
+//!   # #[cfg(feature = "anyhow")]
+//!   # {
+//!   use resultish::Resultish::{self, Both, Err, Ok};
+//!
+//!   let x: Resultish<i32, std::num::ParseIntError> = Ok(3);
+//!   assert_eq!(x.with_context(|| "Parsing failed").into_anyhow_strict().unwrap(), 3);
+//!
+//!   let err = "three".parse::<i32>().unwrap_err();
+//!   let x: Resultish<i32, std::num::ParseIntError> = Both(3, err);
+//!   assert_eq!(x.clone().into_anyhow_lenient().unwrap(), 3);
+//!
+//!   let err = x.with_context(|| "Parsing failed").into_anyhow_strict().unwrap_err();
+//!   let chain: Vec<String> = err.chain().map(|err| err.to_string()).collect();
+//!   assert_eq!(chain, ["Parsing failed", "invalid digit found in string"]);
+//!   # }
+//!   ```
+//! - `log`: Enables `Resultish::unwrap_or_log`, which logs the error value, if any, with
+//!   `log::warn!`, including the error value of [`Both`].
+//!
+//!   ```
+//!   # #[cfg(feature = "log")]
+//!   # {
+//!   use resultish::Resultish::{self, Both, Err, Ok};
+//!   use std::sync::Mutex;
+//!
+//!   static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+//!
+//!   struct CapturingLogger;
+//!
+//!   impl log::Log for CapturingLogger {
+//!       fn enabled(&self, _: &log::Metadata) -> bool {
+//!           true
+//!       }
+//!
+//!       fn log(&self, record: &log::Record) {
+//!           let message = format!("{} {}", record.level(), record.args());
+//!           MESSAGES.lock().unwrap().push(message);
+//!       }
+//!
+//!       fn flush(&self) {}
+//!   }
+//!
+//!   log::set_logger(&CapturingLogger).unwrap();
+//!   log::set_max_level(log::LevelFilter::Warn);
+//!
+//!   let x: Resultish<i32, &str> = Ok(3);
+//!   assert_eq!(x.unwrap_or_log(0), 3);
+//!   assert!(MESSAGES.lock().unwrap().is_empty());
+//!
+//!   let x: Resultish<i32, &str> = Err("Some error message");
+//!   assert_eq!(x.unwrap_or_log(0), 0);
+//!   assert_eq!(*MESSAGES.lock().unwrap(), [r#"WARN "Some error message""#]);
+//!
+//!   let x: Resultish<i32, &str> = Both(3, "Another error message");
+//!   assert_eq!(x.unwrap_or_log(0), 3);
+//!   assert_eq!(
+//!       *MESSAGES.lock().unwrap(),
+//!       [r#"WARN "Some error message""#, r#"WARN "Another error message""#],
+//!   );
+//!   # }
+//!   ```
+//! - `nightly`: Implements the unstable `Try` trait, so that `?` can be used on a [`Resultish`]
+//!   in functions returning a [`Resultish`] or [`Result`]. Requires a nightly compiler. `?` uses
+//!   strict semantics, like [`Resultish::strict`]: [`Err`] and [`Both`] short-circuit with the
+//!   error value, and the success value of [`Both`] is discarded.
+//!
+//!   ```
+//!   # #[cfg(feature = "nightly")]
+//!   # {
+//!   use resultish::Resultish::{self, Both, Err, Ok};
+//!
+//!   fn add<'a>(x: Resultish<i32, &'a str>, y: Resultish<i32, &'a str>) -> Result<i32, &'a str> {
+//!       Result::Ok(x? + y?)
+//!   }
+//!
+//!   assert_eq!(add(Ok(3), Ok(4)), Result::Ok(7));
+//!   assert_eq!(add(Ok(3), Err("Some error message")), Result::Err("Some error message"));
+//!   assert_eq!(add(Ok(3), Both(4, "Some error message")), Result::Err("Some error message"));
+//!
+//!   fn parse(s: &str) -> Resultish<i32, std::num::ParseIntError> {
+//!       Ok(s.parse::<i32>()? * 2)
+//!   }
+//!
+//!   assert_eq!(parse("3"), Ok(6));
+//!   assert!(parse("three").has_err());
+//!   # }
+//!   ```
+//!
+//!   This also allows a [`Resultish`] to be returned from the closure passed to iterator
+//!   methods such as `try_for_each` and `try_fold`. These stop at the first [`Err`] or [`Both`],
+//!   returning its error value, and the success value of [`Both`] is discarded.
+//!
+//!   ```
+//!   # #[cfg(feature = "nightly")]
+//!   # {
+//!   use resultish::Resultish::{self, Both, Err, Ok};
+//!
+//!   fn process(x: i32) -> Resultish<(), &'static str> {
+//!       match x {
+//!           0 => Both((), "Zero"),
+//!           x if x < 0 => Err("Negative"),
+//!           _ => Ok(()),
+//!       }
+//!   }
+//!
+//!   let mut seen = Vec::new();
+//!   let x = [1, 2, 0, 3].into_iter().try_for_each(|x| {
+//!       seen.push(x);
+//!       process(x)
+//!   });
+//!   assert_eq!(x, Err("Zero"));
+//!   assert_eq!(seen, [1, 2, 0]);
+//!
+//!   let x = [1, 2, 3].into_iter().try_fold(0, |acc, x| process(x).map(|()| acc + x));
+//!   assert_eq!(x, Ok(6));
+//!   # }
+//!   ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = "quickcheck")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp;
+#[cfg(feature = "nightly")]
+use core::convert;
+use core::fmt;
+use core::iter::{self, FusedIterator};
+use core::mem;
+use core::ops;
+use core::pin::Pin;
+use core::ptr;
+#[cfg(feature = "std")]
+use std::any::Any;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::panic;
 
 use Resultish::{Both, Err, Ok};
 
 /// `Resultish` represents success ([`Ok`]), error ([`Err`]), or [`Both`].
-#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub enum Resultish<T, E> {
     /// Contains only a success value
@@ -19,136 +268,474 @@ pub enum Resultish<T, E> {
     Both(T, E),
 }
 
+/// How to resolve [`Both`] when converting a [`Resultish`] to a [`Result`]. See
+/// [`Resultish::to_result`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum BothStrategy {
+    /// [`Both`] is mapped to [`Result::Ok`], and the error value is discarded
+    PreferOk,
+    /// [`Both`] is mapped to [`Result::Err`], and the success value is discarded
+    PreferErr,
+}
+
+/// A success or error value yielded by [`Resultish::into_iter_both`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Side<T, E> {
+    /// A success value
+    Ok(T),
+    /// An error value
+    Err(E),
+}
+
+/// Constructs a [`Resultish`] from a success value, an error value, or both.
+///
+/// # Examples
+///
+/// ```
+/// use resultish::resultish;
+/// use resultish::Resultish::{self, Both, Err, Ok};
+///
+/// let x: Resultish<i32, &str> = resultish!(ok = 3);
+/// assert_eq!(x, Ok(3));
+///
+/// let x: Resultish<i32, &str> = resultish!(err = "Some error message");
+/// assert_eq!(x, Err("Some error message"));
+///
+/// let x = resultish!(ok = 3, err = "Some error message");
+/// assert_eq!(x, Both(3, "Some error message"));
+/// ```
+#[macro_export]
+macro_rules! resultish {
+    (ok = $ok:expr $(,)?) => {
+        $crate::Resultish::Ok($ok)
+    };
+    (err = $err:expr $(,)?) => {
+        $crate::Resultish::Err($err)
+    };
+    (ok = $ok:expr, err = $err:expr $(,)?) => {
+        $crate::Resultish::Both($ok, $err)
+    };
+}
+
 impl<T, E> Resultish<T, E> {
-    /// Converts from `&mut Resultish<T, E>` to `Resultish<&mut T, &mut E>`.
-    pub fn as_mut(&mut self) -> Resultish<&mut T, &mut E> {
+    /// Returns `other` if the result contains a success value, otherwise returns the [`Err`] value
+    /// of `self`.
+    ///
+    /// [`Both`] is treated leniently: as it contains a success value, `other` is returned, and the
+    /// error value of `self` is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let ok: Resultish<i32, &str> = Ok(3);
+    /// let err: Resultish<i32, &str> = Err("First");
+    /// let both: Resultish<i32, &str> = Both(3, "First");
+    /// let other_ok: Resultish<char, &str> = Ok('a');
+    /// let other_err: Resultish<char, &str> = Err("Second");
+    /// let other_both: Resultish<char, &str> = Both('a', "Second");
+    ///
+    /// assert_eq!(ok.and(other_ok), Ok('a'));
+    /// assert_eq!(ok.and(other_err), Err("Second"));
+    /// assert_eq!(ok.and(other_both), Both('a', "Second"));
+    ///
+    /// assert_eq!(err.and(other_ok), Err("First"));
+    /// assert_eq!(err.and(other_err), Err("First"));
+    /// assert_eq!(err.and(other_both), Err("First"));
+    ///
+    /// assert_eq!(both.and(other_ok), Ok('a'));
+    /// assert_eq!(both.and(other_err), Err("Second"));
+    /// assert_eq!(both.and(other_both), Both('a', "Second"));
+    /// ```
+    pub fn and<U>(self, other: Resultish<U, E>) -> Resultish<U, E> {
         match self {
-            Ok(ok) => Ok(ok),
+            Ok(_) => other,
             Err(err) => Err(err),
-            Both(ok, err) => Both(ok, err),
+            Both(_, _) => other,
         }
     }
 
-    /// Converts from `&Resultish<T, E>` to `Resultish<&T, &E>`.
-    pub fn as_ref(&self) -> Resultish<&T, &E> {
+    /// Pairs the success values of `self` and `other`, requiring both to contain a success value.
+    ///
+    /// If either is [`Err`], the result is [`Err`]. Otherwise, the result contains the pair of
+    /// success values, and an error value if either contains one. If both contain an error value,
+    /// the error value of `self` is kept, and the error value of `other` is discarded. This is the
+    /// same as [`zip`](Self::zip).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let ok: Resultish<i32, &str> = Ok(3);
+    /// let err: Resultish<i32, &str> = Err("First");
+    /// let both: Resultish<i32, &str> = Both(3, "First");
+    /// let other_ok: Resultish<char, &str> = Ok('a');
+    /// let other_err: Resultish<char, &str> = Err("Second");
+    /// let other_both: Resultish<char, &str> = Both('a', "Second");
+    ///
+    /// assert_eq!(ok.and_both(other_ok), Ok((3, 'a')));
+    /// assert_eq!(ok.and_both(other_err), Err("Second"));
+    /// assert_eq!(ok.and_both(other_both), Both((3, 'a'), "Second"));
+    ///
+    /// assert_eq!(err.and_both(other_ok), Err("First"));
+    /// assert_eq!(err.and_both(other_err), Err("First"));
+    /// assert_eq!(err.and_both(other_both), Err("First"));
+    ///
+    /// assert_eq!(both.and_both(other_ok), Both((3, 'a'), "First"));
+    /// assert_eq!(both.and_both(other_err), Err("First"));
+    /// assert_eq!(both.and_both(other_both), Both((3, 'a'), "First"));
+    /// ```
+    pub fn and_both<U>(self, other: Resultish<U, E>) -> Resultish<(T, U), E> {
+        self.zip(other)
+    }
+
+    /// Calls `op` if the result contains a success value, otherwise returns the [`Err`] value of
+    /// `self`.
+    ///
+    /// For [`Both`], the existing error value is carried over into the result of `op`. Only one
+    /// error value is kept, so if `op` returns an error value too, the newer error value from `op`
+    /// replaces the existing one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// fn half(x: i32) -> Resultish<i32, &'static str> {
+    ///     match x % 2 {
+    ///         0 => Ok(x / 2),
+    ///         _ => Both(x / 2, "Rounded down"),
+    ///     }
+    /// }
+    ///
+    /// fn positive(x: i32) -> Resultish<i32, &'static str> {
+    ///     match x > 0 {
+    ///         true => Ok(x),
+    ///         false => Err("Not positive"),
+    ///     }
+    /// }
+    ///
+    /// let x: Resultish<i32, &str> = Ok(4);
+    /// assert_eq!(x.and_then(half), Ok(2));
+    /// assert_eq!(x.and_then(half).and_then(half), Ok(1));
+    /// assert_eq!(x.and_then(half).and_then(half).and_then(half), Both(0, "Rounded down"));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.and_then(half), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(4, "Some error message");
+    /// assert_eq!(x.and_then(half), Both(2, "Some error message"));
+    /// assert_eq!(x.and_then(half).and_then(half).and_then(half), Both(0, "Rounded down"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(-4, "Some error message");
+    /// assert_eq!(x.and_then(positive), Err("Not positive"));
+    /// ```
+    pub fn and_then<U, F>(self, op: F) -> Resultish<U, E>
+    where
+        F: FnOnce(T) -> Resultish<U, E>,
+    {
         match self {
-            Ok(ok) => Ok(ok),
+            Ok(ok) => op(ok),
             Err(err) => Err(err),
-            Both(ok, err) => Both(ok, err),
+            Both(ok, err) => match op(ok) {
+                Ok(ok) => Both(ok, err),
+                Err(err) => Err(err),
+                Both(ok, err) => Both(ok, err),
+            },
         }
     }
 
-    /// Returns `true` if the result contains a success value.
+    /// Calls `op` if the result contains an error value, otherwise returns the [`Ok`] value of
+    /// `self`. This is the error-side counterpart of [`and_then`](Self::and_then).
+    ///
+    /// For [`Both`], `op` is called with the error value, and the original success value is always
+    /// kept: any success value returned by `op` is discarded. This makes it equivalent to
+    /// [`or_else`](Self::or_else).
     ///
     /// # Examples
     ///
     /// ```
     /// use resultish::Resultish::{self, Both, Err, Ok};
     ///
+    /// fn to_ok(_: &str) -> Resultish<i32, usize> {
+    ///     Ok(0)
+    /// }
+    ///
+    /// fn to_err(err: &str) -> Resultish<i32, usize> {
+    ///     Err(err.len())
+    /// }
+    ///
+    /// fn to_both(err: &str) -> Resultish<i32, usize> {
+    ///     Both(0, err.len())
+    /// }
+    ///
     /// let x: Resultish<i32, &str> = Ok(3);
-    /// assert_eq!(x.has_ok(), true);
+    /// assert_eq!(x.and_then_err(to_ok), Ok(3));
+    /// assert_eq!(x.and_then_err(to_err), Ok(3));
+    /// assert_eq!(x.and_then_err(to_both), Ok(3));
     ///
     /// let x: Resultish<i32, &str> = Err("Some error message");
-    /// assert_eq!(x.has_ok(), false);
+    /// assert_eq!(x.and_then_err(to_ok), Ok(0));
+    /// assert_eq!(x.and_then_err(to_err), Err(18));
+    /// assert_eq!(x.and_then_err(to_both), Both(0, 18));
     ///
     /// let x: Resultish<i32, &str> = Both(3, "Some error message");
-    /// assert_eq!(x.has_ok(), true);
+    /// assert_eq!(x.and_then_err(to_ok), Ok(3));
+    /// assert_eq!(x.and_then_err(to_err), Both(3, 18));
+    /// assert_eq!(x.and_then_err(to_both), Both(3, 18));
     /// ```
-    pub fn has_ok(&self) -> bool {
-        matches!(self, Ok(_) | Both(_, _))
+    pub fn and_then_err<F, O>(self, op: O) -> Resultish<T, F>
+    where
+        O: FnOnce(E) -> Resultish<T, F>,
+    {
+        self.or_else(op)
     }
 
-    /// Returns `true` if the result contains an error value.
+    /// Applies the function contained in `self` to the success value of `value`.
+    ///
+    /// The error values are handled the same way as [`zip`](Self::zip): if both contain an error
+    /// value, the error value of `self` is kept. Use [`apply_with`](Self::apply_with) to keep both.
     ///
     /// # Examples
     ///
     /// ```
     /// use resultish::Resultish::{self, Both, Err, Ok};
     ///
-    /// let x: Resultish<i32, &str> = Ok(3);
-    /// assert_eq!(x.has_err(), false);
+    /// let f: Resultish<fn(i32) -> i32, &str> = Ok(|x| x + 1);
+    /// assert_eq!(f.apply(Ok(3)), Ok(4));
+    /// assert_eq!(f.apply(Both(3, "Second")), Both(4, "Second"));
     ///
-    /// let x: Resultish<i32, &str> = Err("Some error message");
-    /// assert_eq!(x.has_err(), true);
+    /// let f: Resultish<fn(i32) -> i32, &str> = Both(|x| x + 1, "First");
+    /// assert_eq!(f.apply(Ok(3)), Both(4, "First"));
+    /// assert_eq!(f.apply(Both(3, "Second")), Both(4, "First"));
+    /// assert_eq!(f.apply(Err("Second")), Err("First"));
+    /// ```
+    pub fn apply<A, B>(self, value: Resultish<A, E>) -> Resultish<B, E>
+    where
+        T: FnOnce(A) -> B,
+    {
+        self.zip_with(value, |f, a| f(a))
+    }
+
+    /// Applies the function contained in `self` to the success value of `value`, merging the
+    /// error values with `merge_err`.
+    ///
+    /// The error values are handled the same way as [`combine`](Self::combine), so no error value
+    /// is discarded. This allows a value to be built from several validated parts, while
+    /// collecting all of their errors.
+    ///
+    /// # Examples
     ///
-    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
-    /// assert_eq!(x.has_err(), true);
     /// ```
-    pub fn has_err(&self) -> bool {
-        matches!(self, Err(_) | Both(_, _))
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct User {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// fn user(
+    ///     name: Resultish<String, String>,
+    ///     age: Resultish<u32, String>,
+    /// ) -> Resultish<User, String> {
+    ///     let merge = |a: String, b: String| a + ", " + &b;
+    ///     name.map(|name| move |age| User { name, age }).apply_with(age, merge)
+    /// }
+    ///
+    /// let name: Resultish<String, String> = Ok("Alice".to_string());
+    /// let age: Resultish<u32, String> = Ok(30);
+    /// assert_eq!(user(name, age), Ok(User { name: "Alice".to_string(), age: 30 }));
+    ///
+    /// let name: Resultish<String, String> = Both("alice".to_string(), "Lowercase".to_string());
+    /// let age: Resultish<u32, String> = Ok(30);
+    /// assert_eq!(
+    ///     user(name, age),
+    ///     Both(User { name: "alice".to_string(), age: 30 }, "Lowercase".to_string())
+    /// );
+    ///
+    /// let name: Resultish<String, String> = Both("alice".to_string(), "Lowercase".to_string());
+    /// let age: Resultish<u32, String> = Err("Age missing".to_string());
+    /// assert_eq!(user(name, age), Err("Lowercase, Age missing".to_string()));
+    /// ```
+    pub fn apply_with<A, B, FE>(self, value: Resultish<A, E>, merge_err: FE) -> Resultish<B, E>
+    where
+        T: FnOnce(A) -> B,
+        FE: FnOnce(E, E) -> E,
+    {
+        self.combine(value, merge_err).map(|(f, a)| f(a))
     }
 
-    /// Convert to [`Result`] leniently: [`Both`] is mapped to [`Result::Ok`], and the error value
-    /// is discarded.
+    /// Converts from `&Resultish<T, E>` to `Resultish<&T::Target, &E>`.
+    ///
+    /// The success value is dereferenced with [`Deref`](ops::Deref), and the error value is left
+    /// as a reference.
     ///
     /// # Examples
     ///
     /// ```
     /// use resultish::Resultish::{self, Both, Err, Ok};
     ///
-    /// let x: Resultish<i32, &str> = Ok(3);
-    /// assert_eq!(x.lenient(), Result::Ok(3));
+    /// let x: Resultish<String, u32> = Ok("hello".to_string());
+    /// let y: Resultish<&str, &u32> = Ok("hello");
+    /// assert_eq!(x.as_deref(), y);
     ///
-    /// let x: Resultish<i32, &str> = Err("Some error message");
-    /// assert_eq!(x.lenient(), Result::Err("Some error message"));
+    /// let x: Resultish<String, u32> = Err(42);
+    /// let y: Resultish<&str, &u32> = Err(&42);
+    /// assert_eq!(x.as_deref(), y);
     ///
-    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
-    /// assert_eq!(x.lenient(), Result::Ok(3));
+    /// let x: Resultish<String, u32> = Both("hello".to_string(), 42);
+    /// let y: Resultish<&str, &u32> = Both("hello", &42);
+    /// assert_eq!(x.as_deref(), y);
     /// ```
-    pub fn lenient(self) -> Result<T, E> {
-        match self {
-            Ok(ok) => Result::Ok(ok),
-            Err(err) => Result::Err(err),
-            Both(ok, _) => Result::Ok(ok),
-        }
+    pub fn as_deref(&self) -> Resultish<&T::Target, &E>
+    where
+        T: ops::Deref,
+    {
+        self.as_ref().map(|ok| ok.deref())
     }
 
-    /// Equivalent to [`lenient`](`Self::lenient`)`().`[`err`](Result::err)`()`.
-    pub fn lenient_err(self) -> Option<E> {
+    /// Converts from `&mut Resultish<T, E>` to `Resultish<&mut T::Target, &mut E>`.
+    ///
+    /// The success value is dereferenced with [`DerefMut`](ops::DerefMut), and the error value is
+    /// left as a mutable reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<String, u32> = Ok("hello".to_string());
+    /// for ok in x.as_deref_mut() {
+    ///     ok.make_ascii_uppercase();
+    /// }
+    /// assert_eq!(x, Ok("HELLO".to_string()));
+    ///
+    /// let mut x: Resultish<String, u32> = Err(42);
+    /// for ok in x.as_deref_mut() {
+    ///     ok.make_ascii_uppercase();
+    /// }
+    /// assert_eq!(x, Err(42));
+    ///
+    /// let mut x: Resultish<String, u32> = Both("hello".to_string(), 42);
+    /// for ok in x.as_deref_mut() {
+    ///     ok.make_ascii_uppercase();
+    /// }
+    /// assert_eq!(x, Both("HELLO".to_string(), 42));
+    /// ```
+    pub fn as_deref_mut(&mut self) -> Resultish<&mut T::Target, &mut E>
+    where
+        T: ops::DerefMut,
+    {
+        self.as_mut().map(|ok| ok.deref_mut())
+    }
+
+    /// Returns the error value as a [`dyn Error`](Error) if the result contains an error value,
+    /// and [`None`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyError;
+    ///
+    /// impl fmt::Display for MyError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "Some error message")
+    ///     }
+    /// }
+    ///
+    /// impl Error for MyError {}
+    ///
+    /// let x: Resultish<i32, MyError> = Ok(3);
+    /// assert!(x.as_error().is_none());
+    ///
+    /// let x: Resultish<i32, MyError> = Err(MyError);
+    /// assert_eq!(x.as_error().unwrap().to_string(), "Some error message");
+    ///
+    /// let x: Resultish<i32, MyError> = Both(3, MyError);
+    /// assert_eq!(x.as_error().unwrap().to_string(), "Some error message");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn as_error(&self) -> Option<&(dyn Error + 'static)>
+    where
+        E: Error + 'static,
+    {
         match self {
             Ok(_) => None,
             Err(err) => Some(err),
-            Both(_, _) => None,
+            Both(_, err) => Some(err),
         }
     }
 
-    /// Equivalent to [`lenient`](`Self::lenient`)`().`[`ok`](Result::ok)`()`.
-    pub fn lenient_ok(self) -> Option<T> {
+    /// Converts from `&mut Resultish<T, E>` to `Resultish<&mut T, &mut E>`.
+    pub fn as_mut(&mut self) -> Resultish<&mut T, &mut E> {
         match self {
-            Ok(ok) => Some(ok),
-            Err(_) => None,
-            Both(ok, _) => Some(ok),
+            Ok(ok) => Ok(ok),
+            Err(err) => Err(err),
+            Both(ok, err) => Both(ok, err),
         }
     }
 
-    /// Maps a `Resultish<T, E>` to `Resultish<U, E>` by applying a function to the success value,
-    /// and leaving the error value untouched.
-    pub fn map<U, F>(self, op: F) -> Resultish<U, E>
-    where
-        F: FnOnce(T) -> U,
-    {
-        match self {
-            Ok(ok) => Ok(op(ok)),
-            Err(err) => Err(err),
-            Both(ok, err) => Both(op(ok), err),
+    /// Converts from `Pin<&mut Resultish<T, E>>` to `Resultish<Pin<&mut T>, Pin<&mut E>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use std::future::{self, Future};
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let x: Resultish<future::Ready<i32>, &str> = Both(future::ready(3), "Some error message");
+    /// let mut x = pin!(x);
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// match x.as_mut().as_pin_mut() {
+    ///     Ok(fut) | Both(fut, _) => assert_eq!(fut.poll(&mut cx), Poll::Ready(3)),
+    ///     Err(_) => unreachable!(),
+    /// }
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Resultish<Pin<&mut T>, Pin<&mut E>> {
+        // SAFETY: The contained values are pinned, as they are part of `self`, which is pinned, and
+        // they are never moved out.
+        unsafe {
+            match Pin::get_unchecked_mut(self) {
+                Ok(ok) => Ok(Pin::new_unchecked(ok)),
+                Err(err) => Err(Pin::new_unchecked(err)),
+                Both(ok, err) => Both(Pin::new_unchecked(ok), Pin::new_unchecked(err)),
+            }
         }
     }
 
-    /// Maps a `Resultish<T, E>` to `Resultish<T, F>` by applying a function to the error value,
-    /// and leaving the success value untouched.
-    pub fn map_err<F, O>(self, op: O) -> Resultish<T, F>
-    where
-        O: FnOnce(E) -> F,
-    {
+    /// Converts from `Pin<&Resultish<T, E>>` to `Resultish<Pin<&T>, Pin<&E>>`.
+    pub fn as_pin_ref(self: Pin<&Self>) -> Resultish<Pin<&T>, Pin<&E>> {
+        // SAFETY: The contained values are pinned, as they are part of `self`, which is pinned.
+        unsafe {
+            match Pin::get_ref(self) {
+                Ok(ok) => Ok(Pin::new_unchecked(ok)),
+                Err(err) => Err(Pin::new_unchecked(err)),
+                Both(ok, err) => Both(Pin::new_unchecked(ok), Pin::new_unchecked(err)),
+            }
+        }
+    }
+
+    /// Converts from `&Resultish<T, E>` to `Resultish<&T, &E>`.
+    pub fn as_ref(&self) -> Resultish<&T, &E> {
         match self {
             Ok(ok) => Ok(ok),
-            Err(err) => Err(op(err)),
-            Both(ok, err) => Both(ok, op(err)),
+            Err(err) => Err(err),
+            Both(ok, err) => Both(ok, err),
         }
     }
 
-    /// Convert to [`Result`] strictly: [`Both`] is mapped to [`Result::Err`], and the success value
-    /// is discarded.
+    /// Returns the success and error values if the result is [`Both`], and [`None`] otherwise.
     ///
     /// # Examples
     ///
@@ -156,41 +743,51 @@ impl<T, E> Resultish<T, E> {
     /// use resultish::Resultish::{self, Both, Err, Ok};
     ///
     /// let x: Resultish<i32, &str> = Ok(3);
-    /// assert_eq!(x.strict(), Result::Ok(3));
+    /// assert_eq!(x.both(), None);
     ///
     /// let x: Resultish<i32, &str> = Err("Some error message");
-    /// assert_eq!(x.strict(), Result::Err("Some error message"));
+    /// assert_eq!(x.both(), None);
     ///
     /// let x: Resultish<i32, &str> = Both(3, "Some error message");
-    /// assert_eq!(x.strict(), Result::Err("Some error message"));
+    /// assert_eq!(x.both(), Some((3, "Some error message")));
     /// ```
-    pub fn strict(self) -> Result<T, E> {
-        match self {
-            Ok(ok) => Result::Ok(ok),
-            Err(err) => Result::Err(err),
-            Both(_, err) => Result::Err(err),
-        }
-    }
-
-    /// Equivalent to [`strict`](`Self::strict`)`().`[`err`](Result::err)`()`.
-    pub fn strict_err(self) -> Option<E> {
+    pub fn both(self) -> Option<(T, E)> {
         match self {
             Ok(_) => None,
-            Err(err) => Some(err),
-            Both(_, err) => Some(err),
+            Err(_) => None,
+            Both(ok, err) => Some((ok, err)),
         }
     }
 
-    /// Equivalent to [`strict`](`Self::strict`)`().`[`ok`](Result::ok)`()`.
-    pub fn strict_ok(self) -> Option<T> {
+    /// Returns references to the success and error values if the result is [`Both`], and [`None`]
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.both_ref(), None);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.both_ref(), None);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.both_ref(), Some((&3, &"Some error message")));
+    /// ```
+    pub fn both_ref(&self) -> Option<(&T, &E)> {
         match self {
-            Ok(ok) => Some(ok),
+            Ok(_) => None,
             Err(_) => None,
-            Both(_, _) => None,
+            Both(ok, err) => Some((ok, err)),
         }
     }
 
-    /// Convert to tuple of the success and error values.
+    /// Converts [`Both`] into [`Err`], discarding the success value.
+    ///
+    /// [`Ok`] and [`Err`] are left untouched, so this is idempotent. Unlike
+    /// [`strict`](Self::strict), the result is still a `Resultish`.
     ///
     /// # Examples
     ///
@@ -198,84 +795,5234 @@ impl<T, E> Resultish<T, E> {
     /// use resultish::Resultish::{self, Both, Err, Ok};
     ///
     /// let x: Resultish<i32, &str> = Ok(3);
-    /// assert_eq!(x.tuple(), (Some(3), None));
+    /// assert_eq!(x.both_to_err(), Ok(3));
     ///
     /// let x: Resultish<i32, &str> = Err("Some error message");
-    /// assert_eq!(x.tuple(), (None, Some("Some error message")));
+    /// assert_eq!(x.both_to_err(), Err("Some error message"));
     ///
     /// let x: Resultish<i32, &str> = Both(3, "Some error message");
-    /// assert_eq!(x.tuple(), (Some(3), Some("Some error message")));
+    /// assert_eq!(x.both_to_err(), Err("Some error message"));
+    /// assert_eq!(x.both_to_err().both_to_err(), Err("Some error message"));
     /// ```
-    pub fn tuple(self) -> (Option<T>, Option<E>) {
+    pub fn both_to_err(self) -> Resultish<T, E> {
         match self {
-            Ok(ok) => (Some(ok), None),
-            Err(err) => (None, Some(err)),
-            Both(ok, err) => (Some(ok), Some(err)),
+            Ok(ok) => Ok(ok),
+            Err(err) => Err(err),
+            Both(_, err) => Err(err),
         }
     }
-}
 
-impl<T, E> Resultish<&T, E> {
-    /// Maps a `Resultish<&T, E>` to a `Resultish<T, E>` by cloning the contents of the success
-    /// value.
-    pub fn cloned(self) -> Resultish<T, E>
-    where
-        T: Clone,
-    {
+    /// Converts [`Both`] into [`Ok`], discarding the error value.
+    ///
+    /// [`Ok`] and [`Err`] are left untouched, so this is idempotent. Unlike
+    /// [`lenient`](Self::lenient), the result is still a `Resultish`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.both_to_ok(), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.both_to_ok(), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.both_to_ok(), Ok(3));
+    /// assert_eq!(x.both_to_ok().both_to_ok(), Ok(3));
+    /// ```
+    pub fn both_to_ok(self) -> Resultish<T, E> {
         match self {
-            Ok(ok) => Ok(ok.clone()),
+            Ok(ok) => Ok(ok),
             Err(err) => Err(err),
-            Both(ok, err) => Both(ok.clone(), err),
+            Both(ok, _) => Ok(ok),
         }
     }
 
-    /// Maps a `Resultish<&T, E>` to a `Resultish<T, E>` by copying the contents of the success
-    /// value.
-    pub fn copied(self) -> Resultish<T, E>
+    /// Removes the error value from [`Both`] in place, returning it. This is the in-place
+    /// equivalent of [`both_to_ok`](Self::both_to_ok), and the same as
+    /// [`take_err`](Self::take_err).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.clear_err(), None);
+    /// assert_eq!(x, Ok(3));
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.clear_err(), None);
+    /// assert_eq!(x, Err("Some error message"));
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.clear_err(), Some("Some error message"));
+    /// assert_eq!(x, Ok(3));
+    /// ```
+    pub fn clear_err(&mut self) -> Option<E> {
+        self.take_err()
+    }
+
+    /// Removes the success value from [`Both`] in place, returning it. This is the in-place
+    /// equivalent of [`both_to_err`](Self::both_to_err), and the same as
+    /// [`take_ok`](Self::take_ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.clear_ok(), None);
+    /// assert_eq!(x, Ok(3));
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.clear_ok(), None);
+    /// assert_eq!(x, Err("Some error message"));
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.clear_ok(), Some(3));
+    /// assert_eq!(x, Err("Some error message"));
+    /// ```
+    pub fn clear_ok(&mut self) -> Option<T> {
+        self.take_ok()
+    }
+
+    /// Returns a clone of the error value, if any, without cloning the success value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// #[derive(Debug)]
+    /// struct NoClone;
+    ///
+    /// impl Clone for NoClone {
+    ///     fn clone(&self) -> Self {
+    ///         panic!("NoClone was cloned");
+    ///     }
+    /// }
+    ///
+    /// let x: Resultish<NoClone, &str> = Ok(NoClone);
+    /// assert_eq!(x.clone_err(), None);
+    ///
+    /// let x: Resultish<NoClone, &str> = Err("Some error message");
+    /// assert_eq!(x.clone_err(), Some("Some error message"));
+    ///
+    /// let x: Resultish<NoClone, &str> = Both(NoClone, "Some error message");
+    /// assert_eq!(x.clone_err(), Some("Some error message"));
+    /// ```
+    pub fn clone_err(&self) -> Option<E>
     where
-        T: Copy,
+        E: Clone,
     {
         match self {
-            Ok(ok) => Ok(*ok),
-            Err(err) => Err(err),
-            Both(ok, err) => Both(*ok, err),
+            Ok(_) => None,
+            Err(err) => Some(err.clone()),
+            Both(_, err) => Some(err.clone()),
         }
     }
-}
 
-impl<T, E> Resultish<&mut T, E> {
-    /// Maps a `Resultish<&mut T, E>` to a `Resultish<T, E>` by cloning the contents of the success
-    /// value.
-    pub fn cloned(self) -> Resultish<T, E>
+    /// Returns a clone of the success value, if any, without cloning the error value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// #[derive(Debug)]
+    /// struct NoClone;
+    ///
+    /// impl Clone for NoClone {
+    ///     fn clone(&self) -> Self {
+    ///         panic!("NoClone was cloned");
+    ///     }
+    /// }
+    ///
+    /// let x: Resultish<i32, NoClone> = Ok(3);
+    /// assert_eq!(x.clone_ok(), Some(3));
+    ///
+    /// let x: Resultish<i32, NoClone> = Err(NoClone);
+    /// assert_eq!(x.clone_ok(), None);
+    ///
+    /// let x: Resultish<i32, NoClone> = Both(3, NoClone);
+    /// assert_eq!(x.clone_ok(), Some(3));
+    /// ```
+    pub fn clone_ok(&self) -> Option<T>
     where
         T: Clone,
     {
         match self {
-            Ok(ok) => Ok(ok.clone()),
-            Err(err) => Err(err),
-            Both(ok, err) => Both(ok.clone(), err),
+            Ok(ok) => Some(ok.clone()),
+            Err(_) => None,
+            Both(ok, _) => Some(ok.clone()),
         }
     }
 
-    /// Maps a `Resultish<&mut T, E>` to a `Resultish<T, E>` by copying the contents of the success
-    /// value.
-    pub fn copied(self) -> Resultish<T, E>
+    /// Collapses to a `U` by applying `f_ok` to the success value, or `f_err` to the error value.
+    ///
+    /// [`Both`] is treated leniently: `f_ok` is applied to the success value, and the error value
+    /// is discarded. Use [`collapse_strict`](Self::collapse_strict) to apply `f_err` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.collapse(|ok| ok.to_string(), str::to_uppercase), "3");
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.collapse(|ok| ok.to_string(), str::to_uppercase), "SOME ERROR MESSAGE");
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.collapse(|ok| ok.to_string(), str::to_uppercase), "3");
+    /// ```
+    pub fn collapse<U, FO, FE>(self, f_ok: FO, f_err: FE) -> U
     where
-        T: Copy,
+        FO: FnOnce(T) -> U,
+        FE: FnOnce(E) -> U,
     {
         match self {
-            Ok(ok) => Ok(*ok),
-            Err(err) => Err(err),
-            Both(ok, err) => Both(*ok, err),
+            Ok(ok) => f_ok(ok),
+            Err(err) => f_err(err),
+            Both(ok, _) => f_ok(ok),
         }
     }
-}
 
-impl<T, E> From<Result<T, E>> for Resultish<T, E> {
-    fn from(value: Result<T, E>) -> Self {
-        match value {
-            Result::Ok(ok) => Ok(ok),
-            Result::Err(err) => Err(err),
+    /// Collapses to a `U` by applying `f_ok` to the success value, or `f_err` to the error value.
+    ///
+    /// [`Both`] is treated strictly: `f_err` is applied to the error value, and the success value
+    /// is discarded. Use [`collapse`](Self::collapse) to apply `f_ok` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.collapse_strict(|ok| ok.to_string(), str::to_uppercase), "3");
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.collapse_strict(|ok| ok.to_string(), str::to_uppercase), "SOME ERROR MESSAGE");
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.collapse_strict(|ok| ok.to_string(), str::to_uppercase), "SOME ERROR MESSAGE");
+    /// ```
+    pub fn collapse_strict<U, FO, FE>(self, f_ok: FO, f_err: FE) -> U
+    where
+        FO: FnOnce(T) -> U,
+        FE: FnOnce(E) -> U,
+    {
+        match self {
+            Ok(ok) => f_ok(ok),
+            Err(err) => f_err(err),
+            Both(_, err) => f_err(err),
+        }
+    }
+
+    /// Combines `self` with another `Resultish`, pairing the success values, and merging the error
+    /// values with `merge_err`.
+    ///
+    /// The result contains the pair of success values only if both contain a success value. If
+    /// both contain an error value, `merge_err` is called to combine them into one, otherwise the
+    /// error value that is present, if any, is kept.
+    ///
+    /// Unlike [`zip`](Self::zip), no error value is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let ok: Resultish<i32, String> = Ok(3);
+    /// let err: Resultish<i32, String> = Err("a".to_string());
+    /// let both: Resultish<i32, String> = Both(3, "a".to_string());
+    /// let other_ok: Resultish<char, String> = Ok('x');
+    /// let other_err: Resultish<char, String> = Err("b".to_string());
+    /// let other_both: Resultish<char, String> = Both('x', "b".to_string());
+    /// let merge = |a: String, b: String| a + &b;
+    ///
+    /// assert_eq!(ok.clone().combine(other_ok.clone(), merge), Ok((3, 'x')));
+    /// assert_eq!(ok.clone().combine(other_err.clone(), merge), Err("b".to_string()));
+    /// assert_eq!(ok.clone().combine(other_both.clone(), merge), Both((3, 'x'), "b".to_string()));
+    ///
+    /// assert_eq!(err.clone().combine(other_ok.clone(), merge), Err("a".to_string()));
+    /// assert_eq!(err.clone().combine(other_err.clone(), merge), Err("ab".to_string()));
+    /// assert_eq!(err.clone().combine(other_both.clone(), merge), Err("ab".to_string()));
+    ///
+    /// assert_eq!(both.clone().combine(other_ok.clone(), merge), Both((3, 'x'), "a".to_string()));
+    /// assert_eq!(both.clone().combine(other_err.clone(), merge), Err("ab".to_string()));
+    /// assert_eq!(both.clone().combine(other_both.clone(), merge), Both((3, 'x'), "ab".to_string()));
+    /// ```
+    pub fn combine<U, FE>(self, other: Resultish<U, E>, merge_err: FE) -> Resultish<(T, U), E>
+    where
+        FE: FnOnce(E, E) -> E,
+    {
+        match (self, other) {
+            (Ok(ok), Ok(other_ok)) => Ok((ok, other_ok)),
+            (Ok(_), Err(other_err)) => Err(other_err),
+            (Ok(ok), Both(other_ok, other_err)) => Both((ok, other_ok), other_err),
+            (Err(err), Ok(_)) => Err(err),
+            (Err(err), Err(other_err)) => Err(merge_err(err, other_err)),
+            (Err(err), Both(_, other_err)) => Err(merge_err(err, other_err)),
+            (Both(ok, err), Ok(other_ok)) => Both((ok, other_ok), err),
+            (Both(_, err), Err(other_err)) => Err(merge_err(err, other_err)),
+            (Both(ok, err), Both(other_ok, other_err)) => {
+                Both((ok, other_ok), merge_err(err, other_err))
+            }
+        }
+    }
+
+    /// Returns `true` if the result contains a success value equal to `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.contains(&3), true);
+    /// assert_eq!(x.contains(&4), false);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.contains(&3), false);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.contains(&3), true);
+    /// assert_eq!(x.contains_err(&"Some error message"), true);
+    /// ```
+    pub fn contains<U>(&self, x: &U) -> bool
+    where
+        U: PartialEq<T>,
+    {
+        match self {
+            Ok(ok) => x == ok,
+            Err(_) => false,
+            Both(ok, _) => x == ok,
+        }
+    }
+
+    /// Returns `true` if the result contains an error value equal to `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.contains_err(&"Some error message"), false);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.contains_err(&"Some error message"), true);
+    /// assert_eq!(x.contains_err(&"Some other error message"), false);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.contains_err(&"Some error message"), true);
+    /// assert_eq!(x.contains(&3), true);
+    /// ```
+    pub fn contains_err<F>(&self, f: &F) -> bool
+    where
+        F: PartialEq<E>,
+    {
+        match self {
+            Ok(_) => false,
+            Err(err) => f == err,
+            Both(_, err) => f == err,
+        }
+    }
+
+    /// Asserts that the result contains an error value, returning `self` for chaining.
+    ///
+    /// The assertion is only checked when debug assertions are enabled, like [`debug_assert!`].
+    /// Otherwise this does nothing.
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions enabled, panics if the value is an [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both};
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.debug_assert_err().err_ref(), Some(&"Some error message"));
+    /// ```
+    #[track_caller]
+    pub fn debug_assert_err(&self) -> &Self {
+        debug_assert!(
+            self.has_err(),
+            "called `Resultish::debug_assert_err()` on an `Ok` value"
+        );
+        self
+    }
+
+    /// Asserts that the result contains a success value, returning `self` for chaining.
+    ///
+    /// The assertion is only checked when debug assertions are enabled, like [`debug_assert!`].
+    /// Otherwise this does nothing.
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions enabled, panics if the value is an [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both};
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.debug_assert_ok().map(|ok| ok * 2), Both(6, "Some error message"));
+    /// ```
+    #[track_caller]
+    pub fn debug_assert_ok(&self) -> &Self {
+        debug_assert!(
+            self.has_ok(),
+            "called `Resultish::debug_assert_ok()` on an `Err` value"
+        );
+        self
+    }
+
+    /// Returns a small integer identifying the variant: `0` for [`Ok`], `1` for [`Err`], and `2`
+    /// for [`Both`].
+    ///
+    /// This mapping is stable, and will not change between versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.discriminant(), 0);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.discriminant(), 1);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.discriminant(), 2);
+    /// ```
+    pub fn discriminant(&self) -> u8 {
+        match self {
+            Ok(_) => 0,
+            Err(_) => 1,
+            Both(_, _) => 2,
+        }
+    }
+
+    /// Converts from `Resultish<T, E>` to [`Option<E>`], discarding the success value, if any.
+    ///
+    /// This is equivalent to [`strict_err`](Self::strict_err).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.err(), None);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.err(), Some("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.err(), Some("Some error message"));
+    /// ```
+    pub fn err(self) -> Option<E> {
+        match self {
+            Ok(_) => None,
+            Err(err) => Some(err),
+            Both(_, err) => Some(err),
+        }
+    }
+
+    /// Converts to a [`Result`] with the error value as the preferred value: the error value, if
+    /// any, is returned in [`Result::Ok`], otherwise the success value is returned in
+    /// [`Result::Err`].
+    ///
+    /// For [`Both`], the success value is discarded. This is equivalent to
+    /// [`swap`](Self::swap)`().`[`lenient`](Self::lenient)`()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.err_first(), Result::Err(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.err_first(), Result::Ok("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.err_first(), Result::Ok("Some error message"));
+    /// ```
+    pub fn err_first(self) -> Result<E, T> {
+        match self {
+            Ok(ok) => Result::Err(ok),
+            Err(err) => Result::Ok(err),
+            Both(_, err) => Result::Ok(err),
         }
     }
+
+    /// Returns `true` if the result contains an error value that matches the predicate `f`, and
+    /// `false` if there is no error value.
+    ///
+    /// This is the same as [`is_err_and`](Self::is_err_and).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.err_matches(|err| err.starts_with("Some")), false);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.err_matches(|err| err.starts_with("Some")), true);
+    /// assert_eq!(x.err_matches(|err| err.is_empty()), false);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.err_matches(|err| err.starts_with("Some")), true);
+    /// assert_eq!(x.err_matches(|err| err.is_empty()), false);
+    /// ```
+    pub fn err_matches<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&E) -> bool,
+    {
+        self.is_err_and(f)
+    }
+
+    /// Returns a mutable reference to the error value, if any, without consuming `self`.
+    ///
+    /// Equivalent to [`as_mut`](Self::as_mut)`().`[`err`](Self::err)`()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.err_mut(), None);
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// if let Some(err) = x.err_mut() {
+    ///     *err = "Other error message";
+    /// }
+    /// assert_eq!(x, Err("Other error message"));
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// if let Some(err) = x.err_mut() {
+    ///     *err = "Other error message";
+    /// }
+    /// assert_eq!(x, Both(3, "Other error message"));
+    /// ```
+    pub fn err_mut(&mut self) -> Option<&mut E> {
+        match self {
+            Ok(_) => None,
+            Err(err) => Some(err),
+            Both(_, err) => Some(err),
+        }
+    }
+
+    /// Ensures the result contains an error value, by converting [`Ok`] into [`Both`] with the
+    /// provided default error value. [`Err`] and [`Both`] are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.err_or("Default"), Both(3, "Default"));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.err_or("Default"), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.err_or("Default"), Both(3, "Some error message"));
+    /// ```
+    pub fn err_or(self, default_err: E) -> Resultish<T, E> {
+        match self {
+            Ok(ok) => Both(ok, default_err),
+            Err(err) => Err(err),
+            Both(ok, err) => Both(ok, err),
+        }
+    }
+
+    /// Ensures the result contains an error value, by converting [`Ok`] into [`Both`] with an error
+    /// value computed by `f`. [`Err`] and [`Both`] are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, String> = Ok(3);
+    /// assert_eq!(x.err_or_else(|| "Default".to_string()), Both(3, "Default".to_string()));
+    ///
+    /// let x: Resultish<i32, String> = Err("Some error message".to_string());
+    /// assert_eq!(x.err_or_else(|| unreachable!()), Err("Some error message".to_string()));
+    /// ```
+    pub fn err_or_else<F>(self, f: F) -> Resultish<T, E>
+    where
+        F: FnOnce() -> E,
+    {
+        match self {
+            Ok(ok) => Both(ok, f()),
+            Err(err) => Err(err),
+            Both(ok, err) => Both(ok, err),
+        }
+    }
+
+    /// Returns a reference to the error value, if any, without consuming `self`.
+    ///
+    /// Equivalent to [`as_ref`](Self::as_ref)`().`[`err`](Self::err)`()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.err_ref(), None);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.err_ref(), Some(&"Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.err_ref(), Some(&"Some error message"));
+    /// ```
+    pub fn err_ref(&self) -> Option<&E> {
+        match self {
+            Ok(_) => None,
+            Err(err) => Some(err),
+            Both(_, err) => Some(err),
+        }
+    }
+
+    /// Returns a reference to the error value, or `default` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.err_ref_or(&"No error"), &"No error");
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.err_ref_or(&"No error"), &"Some error message");
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.err_ref_or(&"No error"), &"Some error message");
+    /// ```
+    pub fn err_ref_or<'a>(&'a self, default: &'a E) -> &'a E {
+        match self {
+            Ok(_) => default,
+            Err(err) => err,
+            Both(_, err) => err,
+        }
+    }
+
+    /// Returns the contained success value, consuming `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`Err`], with a panic message including the passed message, and
+    /// the error value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.expect("Testing expect"), 3);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.expect("Testing expect"), 3);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resultish::Resultish::{self, Err};
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// x.expect("Testing expect"); // panics with `Testing expect: "Some error message"`
+    /// ```
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> T
+    where
+        E: fmt::Debug,
+    {
+        match self {
+            Ok(ok) => ok,
+            Err(err) => unwrap_failed(msg, &err),
+            Both(ok, _) => ok,
+        }
+    }
+
+    /// Returns the contained success and error values, consuming `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`Ok`] or [`Err`], with a panic message including the passed
+    /// message, and the value with its variant, showing which value is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both};
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.expect_both("Testing expect_both"), (3, "Some error message"));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resultish::Resultish::{self, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// x.expect_both("Testing expect_both"); // panics with `Testing expect_both: Ok(3)`
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resultish::Resultish::{self, Err};
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// // panics with `Testing expect_both: Err("Some error message")`
+    /// x.expect_both("Testing expect_both");
+    /// ```
+    #[track_caller]
+    pub fn expect_both(self, msg: &str) -> (T, E)
+    where
+        T: fmt::Debug,
+        E: fmt::Debug,
+    {
+        match self {
+            x @ Ok(_) => unwrap_failed(msg, &x),
+            x @ Err(_) => unwrap_failed(msg, &x),
+            Both(ok, err) => (ok, err),
+        }
+    }
+
+    /// Returns the contained error value, consuming `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`Ok`], with a panic message including the passed message, and
+    /// the success value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err};
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.expect_err("Testing expect_err"), "Some error message");
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.expect_err("Testing expect_err"), "Some error message");
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resultish::Resultish::{self, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// x.expect_err("Testing expect_err"); // panics with `Testing expect_err: 3`
+    /// ```
+    #[track_caller]
+    pub fn expect_err(self, msg: &str) -> E
+    where
+        T: fmt::Debug,
+    {
+        match self {
+            Ok(ok) => unwrap_failed(msg, &ok),
+            Err(err) => err,
+            Both(_, err) => err,
+        }
+    }
+
+    /// Returns the contained success value, consuming `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`Err`], with the panic message returned by `f`, which is passed
+    /// a reference to the error value. `f` is only called if the value is an [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.expect_with(|err| format!("Testing expect_with: {err}")), 3);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.expect_with(|err| format!("Testing expect_with: {err}")), 3);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// let payload = std::panic::catch_unwind(|| {
+    ///     x.expect_with(|err| format!("Testing expect_with: {err}"));
+    /// })
+    /// .unwrap_err();
+    /// assert_eq!(
+    ///     payload.downcast_ref::<String>().unwrap(),
+    ///     "Testing expect_with: Some error message",
+    /// );
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resultish::Resultish::{self, Err};
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// // panics with `Testing expect_with: Some error message`
+    /// x.expect_with(|err| format!("Testing expect_with: {err}"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[track_caller]
+    pub fn expect_with<F>(self, f: F) -> T
+    where
+        F: FnOnce(&E) -> String,
+    {
+        match self {
+            Ok(ok) => ok,
+            Err(err) => panic!("{}", f(&err)),
+            Both(ok, _) => ok,
+        }
+    }
+
+    /// Checks the success value with `pred`, discarding it if `pred` returns `false`.
+    ///
+    /// If the success value is discarded, [`Ok`] becomes [`Err`] with the provided error value,
+    /// and [`Both`] becomes [`Err`], keeping its existing error value. [`Err`] is returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.filter("Not even", |ok| ok % 2 == 0), Err("Not even"));
+    ///
+    /// let x: Resultish<i32, &str> = Ok(4);
+    /// assert_eq!(x.filter("Not even", |ok| ok % 2 == 0), Ok(4));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.filter("Not even", |ok| ok % 2 == 0), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.filter("Not even", |ok| ok % 2 == 0), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(4, "Some error message");
+    /// assert_eq!(x.filter("Not even", |ok| ok % 2 == 0), Both(4, "Some error message"));
+    /// ```
+    pub fn filter<P>(self, err: E, pred: P) -> Resultish<T, E>
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        self.filter_or_else(|_| err, pred)
+    }
+
+    /// Checks the success value with `pred`, discarding it if `pred` returns `false`.
+    ///
+    /// If the success value is discarded, [`Ok`] becomes [`Err`] with the error value computed by
+    /// applying `f` to the discarded success value, and [`Both`] becomes [`Err`], keeping its
+    /// existing error value. [`Err`] is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let not_even = |ok| format!("{ok} is not even");
+    ///
+    /// let x: Resultish<i32, String> = Ok(3);
+    /// assert_eq!(x.filter_or_else(not_even, |ok| ok % 2 == 0), Err("3 is not even".to_string()));
+    ///
+    /// let x: Resultish<i32, String> = Both(3, "Some error message".to_string());
+    /// assert_eq!(
+    ///     x.filter_or_else(not_even, |ok| ok % 2 == 0),
+    ///     Err("Some error message".to_string()),
+    /// );
+    /// ```
+    pub fn filter_or_else<F, P>(self, f: F, pred: P) -> Resultish<T, E>
+    where
+        F: FnOnce(T) -> E,
+        P: FnOnce(&T) -> bool,
+    {
+        match self {
+            Ok(ok) => match pred(&ok) {
+                true => Ok(ok),
+                false => Err(f(ok)),
+            },
+            Err(err) => Err(err),
+            Both(ok, err) => match pred(&ok) {
+                true => Both(ok, err),
+                false => Err(err),
+            },
+        }
+    }
+
+    /// Computes a `U` from references to the contained values by applying `f_ok`, `f_err`, or
+    /// `f_both`, depending on the variant.
+    ///
+    /// This is the borrowing version of [`map_or_both`](Self::map_or_both), so `self` can still be
+    /// used afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// fn summary(x: &Resultish<i32, &str>) -> String {
+    ///     x.fold_ref(
+    ///         |ok| format!("success: {ok}"),
+    ///         |err| format!("error: {err}"),
+    ///         |ok, err| format!("success: {ok}, with error: {err}"),
+    ///     )
+    /// }
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(summary(&x), "success: 3");
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(summary(&x), "error: Some error message");
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(summary(&x), "success: 3, with error: Some error message");
+    /// assert_eq!(x, Both(3, "Some error message"));
+    /// ```
+    pub fn fold_ref<U, FO, FE, FB>(&self, f_ok: FO, f_err: FE, f_both: FB) -> U
+    where
+        FO: FnOnce(&T) -> U,
+        FE: FnOnce(&E) -> U,
+        FB: FnOnce(&T, &E) -> U,
+    {
+        match self {
+            Ok(ok) => f_ok(ok),
+            Err(err) => f_err(err),
+            Both(ok, err) => f_both(ok, err),
+        }
+    }
+
+    /// Converts from an error value, and a success value which may be absent, to a `Resultish`.
+    ///
+    /// The result is [`Both`] if `ok` is [`Some`], and [`Err`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err};
+    ///
+    /// let x: Resultish<i32, &str> = Resultish::from_option_err(Some(3), "Some error message");
+    /// assert_eq!(x, Both(3, "Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Resultish::from_option_err(None, "Some error message");
+    /// assert_eq!(x, Err("Some error message"));
+    /// ```
+    pub fn from_option_err(ok: Option<T>, err: E) -> Self {
+        match ok {
+            Some(ok) => Both(ok, err),
+            None => Err(err),
+        }
+    }
+
+    /// Converts from a success value, and an error value which may be absent, to a `Resultish`.
+    ///
+    /// The result is [`Both`] if `err` is [`Some`], and [`Ok`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Resultish::from_option_ok(Some("Some error message"), 3);
+    /// assert_eq!(x, Both(3, "Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Resultish::from_option_ok(None, 3);
+    /// assert_eq!(x, Ok(3));
+    /// ```
+    pub fn from_option_ok(err: Option<E>, ok: T) -> Self {
+        match err {
+            Some(err) => Both(ok, err),
+            None => Ok(ok),
+        }
+    }
+
+    /// Converts from a success and error value, each of which may be absent, to a `Resultish`.
+    /// This is the reverse of [`tuple`](Self::tuple).
+    ///
+    /// Returns [`None`] if both values are absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Option<Resultish<i32, &str>> = Resultish::from_tuple(Some(3), None);
+    /// assert_eq!(x, Some(Ok(3)));
+    ///
+    /// let x: Option<Resultish<i32, &str>> = Resultish::from_tuple(None, Some("Some error message"));
+    /// assert_eq!(x, Some(Err("Some error message")));
+    ///
+    /// let x: Option<Resultish<i32, &str>> =
+    ///     Resultish::from_tuple(Some(3), Some("Some error message"));
+    /// assert_eq!(x, Some(Both(3, "Some error message")));
+    ///
+    /// let x: Option<Resultish<i32, &str>> = Resultish::from_tuple(None, None);
+    /// assert_eq!(x, None);
+    /// ```
+    pub fn from_tuple(ok: Option<T>, err: Option<E>) -> Option<Self> {
+        match (ok, err) {
+            (Some(ok), None) => Some(Ok(ok)),
+            (None, Some(err)) => Some(Err(err)),
+            (Some(ok), Some(err)) => Some(Both(ok, err)),
+            (None, None) => None,
+        }
+    }
+
+    /// Inserts `value` as the error value if the result is [`Ok`], converting it into [`Both`],
+    /// then returns a mutable reference to the error value.
+    ///
+    /// An existing error value is not overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, i32> = Ok(3);
+    /// *x.get_or_insert_err(0) += 1;
+    /// assert_eq!(x, Both(3, 1));
+    ///
+    /// let mut x: Resultish<i32, i32> = Err(4);
+    /// *x.get_or_insert_err(0) += 1;
+    /// assert_eq!(x, Err(5));
+    ///
+    /// let mut x: Resultish<i32, i32> = Both(3, 4);
+    /// *x.get_or_insert_err(0) += 1;
+    /// assert_eq!(x, Both(3, 5));
+    /// ```
+    pub fn get_or_insert_err(&mut self, value: E) -> &mut E {
+        if let Ok(_) = self {
+            replace_with(self, |x| match x {
+                Ok(ok) => (Both(ok, value), ()),
+                other => (other, ()),
+            });
+        }
+
+        match self {
+            Ok(_) => unreachable!(),
+            Err(err) => err,
+            Both(_, err) => err,
+        }
+    }
+
+    /// Inserts `value` as the success value if the result is [`Err`], converting it into
+    /// [`Both`], then returns a mutable reference to the success value.
+    ///
+    /// An existing success value is not overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, i32> = Ok(3);
+    /// *x.get_or_insert_ok(0) += 1;
+    /// assert_eq!(x, Ok(4));
+    ///
+    /// let mut x: Resultish<i32, i32> = Err(4);
+    /// *x.get_or_insert_ok(0) += 1;
+    /// assert_eq!(x, Both(1, 4));
+    ///
+    /// let mut x: Resultish<i32, i32> = Both(3, 4);
+    /// *x.get_or_insert_ok(0) += 1;
+    /// assert_eq!(x, Both(4, 4));
+    /// ```
+    pub fn get_or_insert_ok(&mut self, value: T) -> &mut T {
+        if let Err(_) = self {
+            replace_with(self, |x| match x {
+                Err(err) => (Both(value, err), ()),
+                other => (other, ()),
+            });
+        }
+
+        match self {
+            Ok(ok) => ok,
+            Err(_) => unreachable!(),
+            Both(ok, _) => ok,
+        }
+    }
+
+    /// Returns `true` if the result contains a success value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.has_ok(), true);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.has_ok(), false);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.has_ok(), true);
+    /// ```
+    pub fn has_ok(&self) -> bool {
+        matches!(self, Ok(_) | Both(_, _))
+    }
+
+    /// Returns `true` if the result contains an error value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.has_err(), false);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.has_err(), true);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.has_err(), true);
+    /// ```
+    pub fn has_err(&self) -> bool {
+        matches!(self, Err(_) | Both(_, _))
+    }
+
+    /// Calls `f` with a reference to the contained success value, if any, and returns `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// x.inspect(|ok| log.push(format!("ok {ok}")));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// x.inspect(|ok| log.push(format!("ok {ok}")));
+    ///
+    /// let x: Resultish<i32, &str> = Both(4, "Some error message");
+    /// let x = x
+    ///     .inspect(|ok| log.push(format!("ok {ok}")))
+    ///     .inspect_err(|err| log.push(format!("err {err}")));
+    ///
+    /// assert_eq!(x, Both(4, "Some error message"));
+    /// assert_eq!(log, ["ok 3", "ok 4", "err Some error message"]);
+    /// ```
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        match &self {
+            Ok(ok) => f(ok),
+            Err(_) => {}
+            Both(ok, _) => f(ok),
+        }
+        self
+    }
+
+    /// Calls `f` with references to the success and error values if the result is [`Both`], and
+    /// returns `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// x.inspect_both(|ok, err| log.push(format!("ok {ok}, err {err}")));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// x.inspect_both(|ok, err| log.push(format!("ok {ok}, err {err}")));
+    ///
+    /// let x: Resultish<i32, &str> = Both(4, "Some error message");
+    /// let x = x.inspect_both(|ok, err| log.push(format!("ok {ok}, err {err}")));
+    ///
+    /// assert_eq!(x, Both(4, "Some error message"));
+    /// assert_eq!(log, ["ok 4, err Some error message"]);
+    /// ```
+    pub fn inspect_both<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T, &E),
+    {
+        match &self {
+            Ok(_) => {}
+            Err(_) => {}
+            Both(ok, err) => f(ok, err),
+        }
+        self
+    }
+
+    /// Calls `f` with a reference to the contained error value, if any, and returns `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// x.inspect_err(|err| log.push(format!("err {err}")));
+    ///
+    /// let x: Resultish<i32, &str> = Err("First");
+    /// x.inspect_err(|err| log.push(format!("err {err}")));
+    ///
+    /// let x: Resultish<i32, &str> = Both(4, "Second");
+    /// x.inspect_err(|err| log.push(format!("err {err}")));
+    ///
+    /// assert_eq!(log, ["err First", "err Second"]);
+    /// ```
+    pub fn inspect_err<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&E),
+    {
+        match &self {
+            Ok(_) => {}
+            Err(err) => f(err),
+            Both(_, err) => f(err),
+        }
+        self
+    }
+
+    /// Converts to [`anyhow::Result`], where [`Both`] is mapped to [`Result::Ok`], and the error
+    /// value is discarded.
+    #[cfg(feature = "anyhow")]
+    pub fn into_anyhow_lenient(self) -> anyhow::Result<T>
+    where
+        E: Into<anyhow::Error>,
+    {
+        self.lenient().map_err(Into::into)
+    }
+
+    /// Converts to [`anyhow::Result`], where [`Both`] is mapped to [`Result::Err`], and the
+    /// success value is discarded.
+    #[cfg(feature = "anyhow")]
+    pub fn into_anyhow_strict(self) -> anyhow::Result<T>
+    where
+        E: Into<anyhow::Error>,
+    {
+        self.strict().map_err(Into::into)
+    }
+
+    /// Returns a consuming iterator over the possibly contained success and error values.
+    ///
+    /// The iterator yields a [`Side::Ok`] if the result contains a success value, followed by a
+    /// [`Side::Err`] if the result contains an error value. [`Both`] yields two items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use resultish::Side;
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.into_iter_both().len(), 1);
+    /// assert_eq!(x.into_iter_both().collect::<Vec<_>>(), [Side::Ok(3)]);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.into_iter_both().len(), 1);
+    /// assert_eq!(x.into_iter_both().collect::<Vec<_>>(), [Side::Err("Some error message")]);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.into_iter_both().len(), 2);
+    /// assert_eq!(
+    ///     x.into_iter_both().collect::<Vec<_>>(),
+    ///     [Side::Ok(3), Side::Err("Some error message")],
+    /// );
+    /// assert_eq!(
+    ///     x.into_iter_both().rev().collect::<Vec<_>>(),
+    ///     [Side::Err("Some error message"), Side::Ok(3)],
+    /// );
+    /// ```
+    pub fn into_iter_both(self) -> IntoIterBoth<T, E> {
+        let (ok, err) = self.tuple();
+        IntoIterBoth { ok, err }
+    }
+
+    /// Converts to a success value, converting the error value of [`Err`] with [`Into`].
+    ///
+    /// For [`Both`], the original success value is preferred, and the error value is discarded.
+    /// This is the same as [`merge`](Self::merge).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<String, String> = Ok("Success".to_string());
+    /// assert_eq!(x.into_ok(), "Success");
+    ///
+    /// let x: Resultish<String, String> = Err("Failure".to_string());
+    /// assert_eq!(x.into_ok(), "Failure");
+    ///
+    /// let x: Resultish<String, String> = Both("Success".to_string(), "Failure".to_string());
+    /// assert_eq!(x.into_ok(), "Success");
+    /// ```
+    pub fn into_ok(self) -> T
+    where
+        E: Into<T>,
+    {
+        self.merge()
+    }
+
+    /// Converts to a pair of [`Result`]s: the [`lenient`](Self::lenient) result and the
+    /// [`strict`](Self::strict) result.
+    ///
+    /// The two results only differ for [`Both`], which is split into its success value and error
+    /// value without cloning. For [`Ok`] and [`Err`], the contained value is cloned once, as it
+    /// appears in both results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.into_results(), (Result::Ok(3), Result::Ok(3)));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(
+    ///     x.into_results(),
+    ///     (Result::Err("Some error message"), Result::Err("Some error message"))
+    /// );
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.into_results(), (Result::Ok(3), Result::Err("Some error message")));
+    /// ```
+    pub fn into_results(self) -> (Result<T, E>, Result<T, E>)
+    where
+        T: Clone,
+        E: Clone,
+    {
+        match self {
+            Ok(ok) => (Result::Ok(ok.clone()), Result::Ok(ok)),
+            Err(err) => (Result::Err(err.clone()), Result::Err(err)),
+            Both(ok, err) => (Result::Ok(ok), Result::Err(err)),
+        }
+    }
+
+    /// Returns `true` if the result is [`Both`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.is_both(), false);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.is_both(), false);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.is_both(), true);
+    /// assert_eq!(x.has_ok(), true);
+    /// assert_eq!(x.has_err(), true);
+    /// ```
+    pub fn is_both(&self) -> bool {
+        matches!(self, Both(_, _))
+    }
+
+    /// Returns `true` if the result contains an error value, and the error value matches the
+    /// predicate `f`.
+    ///
+    /// For [`Both`], this is independent of [`is_ok_and`](Self::is_ok_and), so both can be `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.is_err_and(|err| err.is_empty()), false);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.is_err_and(|err| err.starts_with("Some")), true);
+    /// assert_eq!(x.is_err_and(|err| err.is_empty()), false);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.is_err_and(|err| err.starts_with("Some")), true);
+    /// assert_eq!(x.is_ok_and(|&ok| ok > 1), true);
+    /// ```
+    pub fn is_err_and<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&E) -> bool,
+    {
+        match self {
+            Ok(_) => false,
+            Err(err) => f(err),
+            Both(_, err) => f(err),
+        }
+    }
+
+    /// Returns `true` if the result is [`Err`], i.e. it contains an error value and no success
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.is_err_only(), false);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.is_err_only(), true);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.is_err_only(), false);
+    /// assert_eq!(x.has_err(), true);
+    /// ```
+    pub fn is_err_only(&self) -> bool {
+        matches!(self, Err(_))
+    }
+
+    /// Returns `true` if the result contains a success value, and the success value matches the
+    /// predicate `f`.
+    ///
+    /// For [`Both`], this is independent of [`is_err_and`](Self::is_err_and), so both can be
+    /// `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.is_ok_and(|&ok| ok > 1), true);
+    /// assert_eq!(x.is_ok_and(|&ok| ok > 5), false);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.is_ok_and(|&ok| ok > 1), false);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.is_ok_and(|&ok| ok > 1), true);
+    /// assert_eq!(x.is_ok_and(|&ok| ok > 5), false);
+    /// assert_eq!(x.is_err_and(|err| err.starts_with("Some")), true);
+    /// ```
+    pub fn is_ok_and<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        match self {
+            Ok(ok) => f(ok),
+            Err(_) => false,
+            Both(ok, _) => f(ok),
+        }
+    }
+
+    /// Returns `true` if the result is [`Ok`], i.e. it contains a success value and no error
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.is_ok_only(), true);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.is_ok_only(), false);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.is_ok_only(), false);
+    /// assert_eq!(x.has_ok(), true);
+    /// ```
+    pub fn is_ok_only(&self) -> bool {
+        matches!(self, Ok(_))
+    }
+
+    /// Returns an iterator over the possibly contained success value.
+    ///
+    /// The iterator yields one value if the result contains a success value, otherwise none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.iter().len(), 1);
+    /// assert_eq!(x.iter().next(), Some(&3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.iter().len(), 0);
+    /// assert_eq!(x.iter().next(), None);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.iter().len(), 1);
+    /// assert_eq!(x.iter().next(), Some(&3));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.as_ref().lenient_ok(),
+        }
+    }
+
+    /// Returns an iterator over the possibly contained error value.
+    ///
+    /// The iterator yields one value if the result contains an error value, otherwise none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.iter_err().len(), 0);
+    /// assert_eq!(x.iter_err().next(), None);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.iter_err().len(), 1);
+    /// assert_eq!(x.iter_err().next(), Some(&"Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.iter_err().len(), 1);
+    /// assert_eq!(x.iter_err().next(), Some(&"Some error message"));
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Err("First"), Both(2, "Second")];
+    /// let errs: Vec<_> = x.iter().flat_map(|x| x.iter_err()).collect();
+    /// assert_eq!(errs, [&"First", &"Second"]);
+    /// ```
+    pub fn iter_err(&self) -> IterErr<'_, E> {
+        IterErr {
+            inner: self.as_ref().err(),
+        }
+    }
+
+    /// Returns a mutable iterator over the possibly contained error value.
+    ///
+    /// The iterator yields one value if the result contains an error value, otherwise none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, i32> = Ok(3);
+    /// assert_eq!(x.iter_err_mut().next(), None);
+    ///
+    /// let mut x: Resultish<i32, i32> = Err(4);
+    /// for err in x.iter_err_mut() {
+    ///     *err += 1;
+    /// }
+    /// assert_eq!(x, Err(5));
+    ///
+    /// let mut x: Resultish<i32, i32> = Both(3, 4);
+    /// assert_eq!(x.iter_err_mut().len(), 1);
+    /// for err in x.iter_err_mut() {
+    ///     *err += 1;
+    /// }
+    /// assert_eq!(x, Both(3, 5));
+    /// ```
+    pub fn iter_err_mut(&mut self) -> IterErrMut<'_, E> {
+        IterErrMut {
+            inner: self.as_mut().err(),
+        }
+    }
+
+    /// Returns a mutable iterator over the possibly contained success value.
+    ///
+    /// The iterator yields one value if the result contains a success value, otherwise none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// for ok in x.iter_mut() {
+    ///     *ok += 1;
+    /// }
+    /// assert_eq!(x, Ok(4));
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.iter_mut().next(), None);
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.iter_mut().len(), 1);
+    /// for ok in x.iter_mut() {
+    ///     *ok += 1;
+    /// }
+    /// assert_eq!(x, Both(4, "Some error message"));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.as_mut().lenient_ok(),
+        }
+    }
+
+    /// Convert to [`Result`] leniently: [`Both`] is mapped to [`Result::Ok`], and the error value
+    /// is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.lenient(), Result::Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.lenient(), Result::Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.lenient(), Result::Ok(3));
+    /// ```
+    pub fn lenient(self) -> Result<T, E> {
+        self.to_result(BothStrategy::PreferOk)
+    }
+
+    /// Equivalent to [`lenient`](`Self::lenient`)`().`[`err`](Result::err)`()`.
+    pub fn lenient_err(self) -> Option<E> {
+        match self {
+            Ok(_) => None,
+            Err(err) => Some(err),
+            Both(_, _) => None,
+        }
+    }
+
+    /// Equivalent to [`lenient`](`Self::lenient`)`().`[`ok`](Result::ok)`()`.
+    pub fn lenient_ok(self) -> Option<T> {
+        match self {
+            Ok(ok) => Some(ok),
+            Err(_) => None,
+            Both(ok, _) => Some(ok),
+        }
+    }
+
+    /// Maps a `Resultish<T, E>` to `Resultish<U, E>` by applying a function to the success value,
+    /// and leaving the error value untouched.
+    pub fn map<U, F>(self, op: F) -> Resultish<U, E>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Ok(ok) => Ok(op(ok)),
+            Err(err) => Err(err),
+            Both(ok, err) => Both(op(ok), err),
+        }
+    }
+
+    /// Maps a `Resultish<T, E>` to `Resultish<U, H>` by applying `f` to the success value and `g`
+    /// to the error value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.map_both(|ok| ok * 2, str::len), Ok(6));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.map_both(|ok| ok * 2, str::len), Err(18));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.map_both(|ok| ok * 2, str::len), Both(6, 18));
+    /// ```
+    pub fn map_both<U, F, G, H>(self, f: F, g: G) -> Resultish<U, H>
+    where
+        F: FnOnce(T) -> U,
+        G: FnOnce(E) -> H,
+    {
+        match self {
+            Ok(ok) => Ok(f(ok)),
+            Err(err) => Err(g(err)),
+            Both(ok, err) => Both(f(ok), g(err)),
+        }
+    }
+
+    /// Maps a `Resultish<T, E>` to `Resultish<T, F>` by applying a function to the error value,
+    /// and leaving the success value untouched.
+    pub fn map_err<F, O>(self, op: O) -> Resultish<T, F>
+    where
+        O: FnOnce(E) -> F,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(err) => Err(op(err)),
+            Both(ok, err) => Both(ok, op(err)),
+        }
+    }
+
+    /// Converts the error value with [`Into`], leaving the success value untouched.
+    ///
+    /// Equivalent to [`map_err`](Self::map_err)`(Into::into)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.map_err_into::<String>(), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// let x: Resultish<i32, String> = x.map_err_into();
+    /// assert_eq!(x, Err("Some error message".to_string()));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// let x: Resultish<i32, String> = x.map_err_into();
+    /// assert_eq!(x, Both(3, "Some error message".to_string()));
+    /// ```
+    pub fn map_err_into<F>(self) -> Resultish<T, F>
+    where
+        E: Into<F>,
+    {
+        self.map_err(Into::into)
+    }
+
+    /// Maps a `Resultish<T, E>` to `Resultish<T, F>` by applying a function to the error value,
+    /// which also receives a reference to the success value, if any.
+    ///
+    /// This allows the error value of [`Both`] to be enriched with context from the success value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let describe = |ok: Option<&i32>, err: &str| match ok {
+    ///     Some(ok) => format!("{err} (after reading {ok} items)"),
+    ///     None => err.to_string(),
+    /// };
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.map_err_with_ok(describe), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.map_err_with_ok(describe), Err("Some error message".to_string()));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(
+    ///     x.map_err_with_ok(describe),
+    ///     Both(3, "Some error message (after reading 3 items)".to_string())
+    /// );
+    /// ```
+    pub fn map_err_with_ok<F, O>(self, op: O) -> Resultish<T, F>
+    where
+        O: FnOnce(Option<&T>, E) -> F,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(err) => Err(op(None, err)),
+            Both(ok, err) => {
+                let err = op(Some(&ok), err);
+                Both(ok, err)
+            }
+        }
+    }
+
+    /// Converts the success value with [`Into`], leaving the error value untouched.
+    ///
+    /// This is the same as [`map_ok_into`](Self::map_ok_into).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<u32, &str> = Ok(3);
+    /// assert_eq!(x.map_into::<u64>(), Ok(3u64));
+    ///
+    /// let x: Resultish<u32, &str> = Err("Some error message");
+    /// assert_eq!(x.map_into::<u64>(), Err("Some error message"));
+    ///
+    /// let x: Resultish<u32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.map_into::<u64>(), Both(3u64, "Some error message"));
+    /// ```
+    pub fn map_into<U>(self) -> Resultish<U, E>
+    where
+        T: Into<U>,
+    {
+        self.map_ok_into()
+    }
+
+    /// Converts the success value with [`Into`], leaving the error value untouched.
+    ///
+    /// Equivalent to [`map`](Self::map)`(Into::into)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.map_ok_into::<i64>(), Ok(3i64));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.map_ok_into::<i64>(), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.map_ok_into::<i64>(), Both(3i64, "Some error message"));
+    /// ```
+    pub fn map_ok_into<U>(self) -> Resultish<U, E>
+    where
+        T: Into<U>,
+    {
+        self.map(Into::into)
+    }
+
+    /// Returns the provided default if the result is [`Err`], or applies a function to the success
+    /// value.
+    ///
+    /// [`Both`] is treated leniently: the function is applied to the success value, and the error
+    /// value is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.map_or(0, |ok| ok * 2), 6);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.map_or(0, |ok| ok * 2), 0);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.map_or(0, |ok| ok * 2), 6);
+    /// ```
+    pub fn map_or<U, F>(self, default: U, f: F) -> U
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Ok(ok) => f(ok),
+            Err(_) => default,
+            Both(ok, _) => f(ok),
+        }
+    }
+
+    /// Maps a `Resultish<T, E>` to `U` by applying `f_ok`, `f_err`, or `f_both`, depending on the
+    /// variant.
+    ///
+    /// Unlike [`map_or_else`](Self::map_or_else), [`Both`] is handled by its own function, rather
+    /// than being treated leniently or strictly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// fn describe(x: Resultish<i32, &str>) -> String {
+    ///     x.map_or_both(
+    ///         |ok| format!("success: {ok}"),
+    ///         |err| format!("error: {err}"),
+    ///         |ok, err| format!("success: {ok}, with error: {err}"),
+    ///     )
+    /// }
+    ///
+    /// assert_eq!(describe(Ok(3)), "success: 3");
+    /// assert_eq!(describe(Err("Some error message")), "error: Some error message");
+    /// assert_eq!(
+    ///     describe(Both(3, "Some error message")),
+    ///     "success: 3, with error: Some error message",
+    /// );
+    /// ```
+    pub fn map_or_both<U, FO, FE, FB>(self, f_ok: FO, f_err: FE, f_both: FB) -> U
+    where
+        FO: FnOnce(T) -> U,
+        FE: FnOnce(E) -> U,
+        FB: FnOnce(T, E) -> U,
+    {
+        match self {
+            Ok(ok) => f_ok(ok),
+            Err(err) => f_err(err),
+            Both(ok, err) => f_both(ok, err),
+        }
+    }
+
+    /// Returns the default value of `U` if the result is [`Err`], or applies a function to the
+    /// success value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let bytes = |s: &str| s.as_bytes().to_vec();
+    ///
+    /// let x: Resultish<&str, &str> = Ok("ab");
+    /// assert_eq!(x.map_or_default(bytes), vec![b'a', b'b']);
+    ///
+    /// let x: Resultish<&str, &str> = Err("Some error message");
+    /// assert_eq!(x.map_or_default(bytes), Vec::<u8>::new());
+    ///
+    /// let x: Resultish<&str, &str> = Both("ab", "Some error message");
+    /// assert_eq!(x.map_or_default(bytes), vec![b'a', b'b']);
+    /// ```
+    pub fn map_or_default<U, F>(self, f: F) -> U
+    where
+        U: Default,
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Ok(ok) => f(ok),
+            Err(_) => U::default(),
+            Both(ok, _) => f(ok),
+        }
+    }
+
+    /// Maps a `Resultish<T, E>` to `U` by applying `default` to the error value if the result is
+    /// [`Err`], or `f` to the success value otherwise.
+    ///
+    /// [`Both`] is treated leniently: `f` is applied to the success value, and the error value is
+    /// discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.map_or_else(|err| err.len(), |ok| ok as usize * 2), 6);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.map_or_else(|err| err.len(), |ok| ok as usize * 2), 18);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.map_or_else(|err| err.len(), |ok| ok as usize * 2), 6);
+    /// ```
+    pub fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce(E) -> U,
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Ok(ok) => f(ok),
+            Err(err) => default(err),
+            Both(ok, _) => f(ok),
+        }
+    }
+
+    /// Collapses a `Resultish<T, E>` to a `T`, converting the error value of [`Err`] with
+    /// [`Into`].
+    ///
+    /// [`Both`] is treated leniently: the success value is returned, and the error value is
+    /// discarded. Use [`merge_with`](Self::merge_with) to combine the values of [`Both`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<String, String> = Ok("Success".to_string());
+    /// assert_eq!(x.merge(), "Success");
+    ///
+    /// let x: Resultish<String, String> = Err("Failure".to_string());
+    /// assert_eq!(x.merge(), "Failure");
+    ///
+    /// let x: Resultish<String, String> = Both("Success".to_string(), "Failure".to_string());
+    /// assert_eq!(x.merge(), "Success");
+    /// ```
+    pub fn merge(self) -> T
+    where
+        E: Into<T>,
+    {
+        match self {
+            Ok(ok) => ok,
+            Err(err) => err.into(),
+            Both(ok, _) => ok,
+        }
+    }
+
+    /// Collapses a `Resultish<T, E>` to a `T`, converting the error value of [`Err`] with
+    /// [`Into`], and combining the values of [`Both`] with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let join = |ok: String, err: String| format!("{ok} and {err}");
+    ///
+    /// let x: Resultish<String, String> = Ok("Success".to_string());
+    /// assert_eq!(x.merge_with(join), "Success");
+    ///
+    /// let x: Resultish<String, String> = Err("Failure".to_string());
+    /// assert_eq!(x.merge_with(join), "Failure");
+    ///
+    /// let x: Resultish<String, String> = Both("Success".to_string(), "Failure".to_string());
+    /// assert_eq!(x.merge_with(join), "Success and Failure");
+    /// ```
+    pub fn merge_with<F>(self, f: F) -> T
+    where
+        E: Into<T>,
+        F: FnOnce(T, E) -> T,
+    {
+        match self {
+            Ok(ok) => ok,
+            Err(err) => err.into(),
+            Both(ok, err) => f(ok, err),
+        }
+    }
+
+    /// Converts from `Resultish<T, E>` to [`Option<T>`], discarding the error value, if any.
+    ///
+    /// This is equivalent to [`lenient_ok`](Self::lenient_ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.ok(), Some(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.ok(), None);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.ok(), Some(3));
+    /// ```
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Ok(ok) => Some(ok),
+            Err(_) => None,
+            Both(ok, _) => Some(ok),
+        }
+    }
+
+    /// Returns `true` if the result contains a success value that matches the predicate `f`, and
+    /// `false` if there is no success value.
+    ///
+    /// This is the same as [`is_ok_and`](Self::is_ok_and).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.ok_matches(|&ok| ok > 1), true);
+    /// assert_eq!(x.ok_matches(|&ok| ok > 5), false);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.ok_matches(|&ok| ok > 1), false);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.ok_matches(|&ok| ok > 1), true);
+    /// assert_eq!(x.ok_matches(|&ok| ok > 5), false);
+    /// ```
+    pub fn ok_matches<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        self.is_ok_and(f)
+    }
+
+    /// Returns a mutable reference to the success value, if any, without consuming `self`.
+    ///
+    /// Equivalent to [`as_mut`](Self::as_mut)`().`[`ok`](Self::ok)`()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// if let Some(ok) = x.ok_mut() {
+    ///     *ok = 4;
+    /// }
+    /// assert_eq!(x, Ok(4));
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.ok_mut(), None);
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// if let Some(ok) = x.ok_mut() {
+    ///     *ok = 4;
+    /// }
+    /// assert_eq!(x, Both(4, "Some error message"));
+    /// ```
+    pub fn ok_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Ok(ok) => Some(ok),
+            Err(_) => None,
+            Both(ok, _) => Some(ok),
+        }
+    }
+
+    /// Ensures the result contains a success value, by converting [`Err`] into [`Both`] with the
+    /// provided default success value. [`Ok`] and [`Both`] are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.ok_or(0), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.ok_or(0), Both(0, "Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.ok_or(0), Both(3, "Some error message"));
+    /// ```
+    pub fn ok_or(self, default_ok: T) -> Resultish<T, E> {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(err) => Both(default_ok, err),
+            Both(ok, err) => Both(ok, err),
+        }
+    }
+
+    /// Ensures the result contains a success value, by converting [`Err`] into [`Both`] with a
+    /// success value computed by `f`. [`Ok`] and [`Both`] are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<Vec<i32>, &str> = Ok(vec![3]);
+    /// assert_eq!(x.ok_or_else(|| unreachable!()), Ok(vec![3]));
+    ///
+    /// let x: Resultish<Vec<i32>, &str> = Err("Some error message");
+    /// assert_eq!(x.ok_or_else(Vec::new), Both(vec![], "Some error message"));
+    /// ```
+    pub fn ok_or_else<F>(self, f: F) -> Resultish<T, E>
+    where
+        F: FnOnce() -> T,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(err) => Both(f(), err),
+            Both(ok, err) => Both(ok, err),
+        }
+    }
+
+    /// Returns a reference to the success value, if any, without consuming `self`.
+    ///
+    /// Equivalent to [`as_ref`](Self::as_ref)`().`[`ok`](Self::ok)`()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.ok_ref(), Some(&3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.ok_ref(), None);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.ok_ref(), Some(&3));
+    /// ```
+    pub fn ok_ref(&self) -> Option<&T> {
+        match self {
+            Ok(ok) => Some(ok),
+            Err(_) => None,
+            Both(ok, _) => Some(ok),
+        }
+    }
+
+    /// Returns a reference to the success value, or `default` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.ok_ref_or(&0), &3);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.ok_ref_or(&0), &0);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.ok_ref_or(&0), &3);
+    /// ```
+    pub fn ok_ref_or<'a>(&'a self, default: &'a T) -> &'a T {
+        match self {
+            Ok(ok) => ok,
+            Err(_) => default,
+            Both(ok, _) => ok,
+        }
+    }
+
+    /// Returns `other` if the result is [`Err`], otherwise returns the success value of `self`.
+    ///
+    /// [`Both`] is treated leniently: as it contains a success value, [`Ok`] is returned with the
+    /// success value of `self`, and the error value of `self` is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let ok: Resultish<i32, &str> = Ok(3);
+    /// let err: Resultish<i32, &str> = Err("First");
+    /// let both: Resultish<i32, &str> = Both(3, "First");
+    /// let other_ok: Resultish<i32, char> = Ok(4);
+    /// let other_err: Resultish<i32, char> = Err('b');
+    /// let other_both: Resultish<i32, char> = Both(4, 'b');
+    ///
+    /// assert_eq!(ok.or(other_ok), Ok(3));
+    /// assert_eq!(ok.or(other_err), Ok(3));
+    /// assert_eq!(ok.or(other_both), Ok(3));
+    ///
+    /// assert_eq!(err.or(other_ok), Ok(4));
+    /// assert_eq!(err.or(other_err), Err('b'));
+    /// assert_eq!(err.or(other_both), Both(4, 'b'));
+    ///
+    /// assert_eq!(both.or(other_ok), Ok(3));
+    /// assert_eq!(both.or(other_err), Ok(3));
+    /// assert_eq!(both.or(other_both), Ok(3));
+    /// ```
+    pub fn or<F>(self, other: Resultish<T, F>) -> Resultish<T, F> {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(_) => other,
+            Both(ok, _) => Ok(ok),
+        }
+    }
+
+    /// Calls `op` if the result contains an error value, otherwise returns the [`Ok`] value of
+    /// `self`.
+    ///
+    /// For [`Both`], the existing success value is always kept, and `op` is only used to recover
+    /// or transform the error value: any success value returned by `op` is discarded, and the
+    /// result is [`Ok`] if `op` returns no error value, or [`Both`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// fn retry(e: &str) -> Resultish<i32, String> {
+    ///     match e {
+    ///         "Timeout" => Ok(0),
+    ///         _ => Err(format!("Failed: {e}")),
+    ///     }
+    /// }
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.or_else(retry), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Timeout");
+    /// assert_eq!(x.or_else(retry), Ok(0));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.or_else(retry), Err("Failed: Some error message".to_string()));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Timeout");
+    /// assert_eq!(x.or_else(retry), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.or_else(retry), Both(3, "Failed: Some error message".to_string()));
+    /// ```
+    pub fn or_else<F, O>(self, op: O) -> Resultish<T, F>
+    where
+        O: FnOnce(E) -> Resultish<T, F>,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(err) => op(err),
+            Both(ok, err) => match op(err) {
+                Ok(_) => Ok(ok),
+                Err(err) => Both(ok, err),
+                Both(_, err) => Both(ok, err),
+            },
+        }
+    }
+
+    /// Partitions `iter` into the success values of [`Ok`], the error values of [`Err`], and the
+    /// pairs of values of [`Both`], preserving their order.
+    ///
+    /// Unlike `ResultishIterExt::partition_resultish`, this keeps the values of [`Both`] apart,
+    /// for example to report which items succeeded with warnings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Vec<Resultish<i32, &str>> =
+    ///     vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3), Both(4, "Third")];
+    /// let (oks, errs, boths) = Resultish::partition(x);
+    /// assert_eq!(oks, [1, 3]);
+    /// assert_eq!(errs, ["Second"]);
+    /// assert_eq!(boths, [(2, "First"), (4, "Third")]);
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![];
+    /// let (oks, errs, boths) = Resultish::partition(x);
+    /// assert!(oks.is_empty() && errs.is_empty() && boths.is_empty());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn partition<I>(iter: I) -> (Vec<T>, Vec<E>, Vec<(T, E)>)
+    where
+        I: IntoIterator<Item = Resultish<T, E>>,
+    {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        let mut boths = Vec::new();
+        for item in iter {
+            match item {
+                Ok(ok) => oks.push(ok),
+                Err(err) => errs.push(err),
+                Both(ok, err) => boths.push((ok, err)),
+            }
+        }
+        (oks, errs, boths)
+    }
+
+    /// Attempts to recover from the error value with `f`, which either returns a success value, or
+    /// hands back an error value.
+    ///
+    /// For [`Err`], if `f` returns [`Result::Ok`], the result becomes [`Ok`] with the recovered
+    /// value, otherwise it stays [`Err`] with the returned error value. For [`Both`], the existing
+    /// success value is kept: if `f` returns [`Result::Ok`], the error value is cleared and the
+    /// recovered value is discarded, otherwise the error value is replaced with the returned one.
+    /// [`Ok`] is returned unchanged, without calling `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// fn recover(err: &str) -> Result<i32, &str> {
+    ///     match err {
+    ///         "Not found" => Result::Ok(0),
+    ///         err => Result::Err(err),
+    ///     }
+    /// }
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.recover(recover), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Not found");
+    /// assert_eq!(x.recover(recover), Ok(0));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.recover(recover), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Not found");
+    /// assert_eq!(x.recover(recover), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.recover(recover), Both(3, "Some error message"));
+    /// ```
+    pub fn recover<F>(self, f: F) -> Resultish<T, E>
+    where
+        F: FnOnce(E) -> Result<T, E>,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(err) => Resultish::from(f(err)),
+            Both(ok, err) => match f(err) {
+                Result::Ok(_) => Ok(ok),
+                Result::Err(err) => Both(ok, err),
+            },
+        }
+    }
+
+    /// Reduces an iterator of results into one, combining all success values with `combine_ok`
+    /// and all error values with `combine_err`.
+    ///
+    /// The result is [`Both`] if any success value and any error value were seen, even if they
+    /// came from different items. Returns [`None`] if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let add = |a: i32, b: i32| a + b;
+    /// let concat = |a: String, b: String| a + ", " + &b;
+    ///
+    /// let x: Vec<Resultish<i32, String>> = vec![Ok(1), Ok(2)];
+    /// assert_eq!(Resultish::reduce(x, add, concat), Some(Ok(3)));
+    ///
+    /// let x: Vec<Resultish<i32, String>> = vec![Err("First".to_string()), Err("Second".to_string())];
+    /// assert_eq!(Resultish::reduce(x, add, concat), Some(Err("First, Second".to_string())));
+    ///
+    /// let x: Vec<Resultish<i32, String>> = vec![
+    ///     Ok(1),
+    ///     Err("First".to_string()),
+    ///     Both(2, "Second".to_string()),
+    ///     Ok(3),
+    /// ];
+    /// assert_eq!(Resultish::reduce(x, add, concat), Some(Both(6, "First, Second".to_string())));
+    ///
+    /// let x: Vec<Resultish<i32, String>> = vec![];
+    /// assert_eq!(Resultish::reduce(x, add, concat), None);
+    /// ```
+    pub fn reduce<I, FT, FE>(iter: I, mut combine_ok: FT, mut combine_err: FE) -> Option<Self>
+    where
+        I: IntoIterator<Item = Resultish<T, E>>,
+        FT: FnMut(T, T) -> T,
+        FE: FnMut(E, E) -> E,
+    {
+        let mut acc_ok = None;
+        let mut acc_err = None;
+        for item in iter {
+            let (ok, err) = item.tuple();
+            acc_ok = match (acc_ok, ok) {
+                (Some(acc_ok), Some(ok)) => Some(combine_ok(acc_ok, ok)),
+                (acc_ok, ok) => acc_ok.or(ok),
+            };
+            acc_err = match (acc_err, err) {
+                (Some(acc_err), Some(err)) => Some(combine_err(acc_err, err)),
+                (acc_err, err) => acc_err.or(err),
+            };
+        }
+        Self::from_tuple(acc_ok, acc_err)
+    }
+
+    /// Replaces the success value with `value`, returning the old success value, if any.
+    ///
+    /// [`Err`] becomes [`Both`], keeping the error value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.replace(4), Some(3));
+    /// assert_eq!(x, Ok(4));
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.replace(4), None);
+    /// assert_eq!(x, Both(4, "Some error message"));
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.replace(4), Some(3));
+    /// assert_eq!(x, Both(4, "Some error message"));
+    /// ```
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        replace_with(self, |x| match x {
+            Ok(ok) => (Ok(value), Some(ok)),
+            Err(err) => (Both(value, err), None),
+            Both(ok, err) => (Both(value, err), Some(ok)),
+        })
+    }
+
+    /// Replaces the error value with `value`, returning the old error value, if any.
+    ///
+    /// [`Ok`] becomes [`Both`], keeping the success value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.replace_err("New error message"), None);
+    /// assert_eq!(x, Both(3, "New error message"));
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.replace_err("New error message"), Some("Some error message"));
+    /// assert_eq!(x, Err("New error message"));
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.replace_err("New error message"), Some("Some error message"));
+    /// assert_eq!(x, Both(3, "New error message"));
+    /// ```
+    pub fn replace_err(&mut self, value: E) -> Option<E> {
+        replace_with(self, |x| match x {
+            Ok(ok) => (Both(ok, value), None),
+            Err(err) => (Err(value), Some(err)),
+            Both(ok, err) => (Both(ok, value), Some(err)),
+        })
+    }
+
+    /// Keeps the error value of [`Both`] only if it matches the predicate `pred`, otherwise
+    /// converts [`Both`] into [`Ok`].
+    ///
+    /// As a `Resultish` must contain at least one value, [`Err`] is always returned unchanged,
+    /// even if `pred` returns `false`. [`Ok`] is returned unchanged, without calling `pred`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let is_fatal = |err: &&str| err.starts_with("Fatal");
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.retain_err_if(is_fatal), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.retain_err_if(is_fatal), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.retain_err_if(is_fatal), Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Fatal error message");
+    /// assert_eq!(x.retain_err_if(is_fatal), Both(3, "Fatal error message"));
+    /// ```
+    pub fn retain_err_if<P>(self, pred: P) -> Resultish<T, E>
+    where
+        P: FnOnce(&E) -> bool,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(err) => Err(err),
+            Both(ok, err) => match pred(&err) {
+                true => Both(ok, err),
+                false => Ok(ok),
+            },
+        }
+    }
+
+    /// Keeps the success value of [`Both`] only if it matches the predicate `pred`, otherwise
+    /// converts [`Both`] into [`Err`].
+    ///
+    /// As a `Resultish` must contain at least one value, [`Ok`] is always returned unchanged, even
+    /// if `pred` returns `false`. [`Err`] is returned unchanged, without calling `pred`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let is_positive = |ok: &i32| *ok > 0;
+    ///
+    /// let x: Resultish<i32, &str> = Ok(-3);
+    /// assert_eq!(x.retain_ok_if(is_positive), Ok(-3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.retain_ok_if(is_positive), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(-3, "Some error message");
+    /// assert_eq!(x.retain_ok_if(is_positive), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.retain_ok_if(is_positive), Both(3, "Some error message"));
+    /// ```
+    pub fn retain_ok_if<P>(self, pred: P) -> Resultish<T, E>
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(err) => Err(err),
+            Both(ok, err) => match pred(&ok) {
+                true => Both(ok, err),
+                false => Err(err),
+            },
+        }
+    }
+
+    /// Splits into the success and error values. This is the same as [`tuple`](Self::tuple).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both};
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// let (ok, err) = x.split();
+    /// assert_eq!(ok, Some(3));
+    /// assert_eq!(err, Some("Some error message"));
+    /// ```
+    pub fn split(self) -> (Option<T>, Option<E>) {
+        self.tuple()
+    }
+
+    /// Splits into references to the success and error values, without consuming `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// let (ok, err) = x.split_ref();
+    /// assert_eq!(ok, Some(&3));
+    /// assert_eq!(err, None);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// let (ok, err) = x.split_ref();
+    /// assert_eq!(ok, None);
+    /// assert_eq!(err, Some(&"Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// let (ok, err) = x.split_ref();
+    /// assert_eq!(ok, Some(&3));
+    /// assert_eq!(err, Some(&"Some error message"));
+    /// assert_eq!(x, Both(3, "Some error message"));
+    /// ```
+    pub fn split_ref(&self) -> (Option<&T>, Option<&E>) {
+        match self {
+            Ok(ok) => (Some(ok), None),
+            Err(err) => (None, Some(err)),
+            Both(ok, err) => (Some(ok), Some(err)),
+        }
+    }
+
+    /// Convert to [`Result`] strictly: [`Both`] is mapped to [`Result::Err`], and the success value
+    /// is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.strict(), Result::Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.strict(), Result::Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.strict(), Result::Err("Some error message"));
+    /// ```
+    pub fn strict(self) -> Result<T, E> {
+        self.to_result(BothStrategy::PreferErr)
+    }
+
+    /// Equivalent to [`strict`](`Self::strict`)`().`[`err`](Result::err)`()`.
+    pub fn strict_err(self) -> Option<E> {
+        match self {
+            Ok(_) => None,
+            Err(err) => Some(err),
+            Both(_, err) => Some(err),
+        }
+    }
+
+    /// Equivalent to [`strict`](`Self::strict`)`().`[`ok`](Result::ok)`()`.
+    pub fn strict_ok(self) -> Option<T> {
+        match self {
+            Ok(ok) => Some(ok),
+            Err(_) => None,
+            Both(_, _) => None,
+        }
+    }
+
+    /// Exchanges the success and error values, converting from `Resultish<T, E>` to
+    /// `Resultish<E, T>`.
+    ///
+    /// Swapping twice returns the original value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.swap(), Err(3));
+    /// assert_eq!(x.swap().swap(), x);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.swap(), Ok("Some error message"));
+    /// assert_eq!(x.swap().swap(), x);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.swap(), Both("Some error message", 3));
+    /// assert_eq!(x.swap().swap(), x);
+    /// ```
+    pub fn swap(self) -> Resultish<E, T> {
+        match self {
+            Ok(ok) => Err(ok),
+            Err(err) => Ok(err),
+            Both(ok, err) => Both(err, ok),
+        }
+    }
+
+    /// Takes the error value out of [`Both`], leaving [`Ok`] with the success value.
+    ///
+    /// As a `Resultish` must contain at least one value, [`Ok`] and [`Err`] are left unchanged,
+    /// and [`None`] is returned.
+    ///
+    /// This is the in-place equivalent of [`both_to_ok`](Self::both_to_ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.take_err(), None);
+    /// assert_eq!(x, Ok(3));
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.take_err(), None);
+    /// assert_eq!(x, Err("Some error message"));
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.take_err(), Some("Some error message"));
+    /// assert_eq!(x, Ok(3));
+    /// ```
+    pub fn take_err(&mut self) -> Option<E> {
+        replace_with(self, |x| match x {
+            Ok(ok) => (Ok(ok), None),
+            Err(err) => (Err(err), None),
+            Both(ok, err) => (Ok(ok), Some(err)),
+        })
+    }
+
+    /// Takes the success value out of [`Both`], leaving [`Err`] with the error value.
+    ///
+    /// As a `Resultish` must contain at least one value, [`Ok`] and [`Err`] are left unchanged,
+    /// and [`None`] is returned.
+    ///
+    /// This is the in-place equivalent of [`both_to_err`](Self::both_to_err).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.take_ok(), None);
+    /// assert_eq!(x, Ok(3));
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.take_ok(), None);
+    /// assert_eq!(x, Err("Some error message"));
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.take_ok(), Some(3));
+    /// assert_eq!(x, Err("Some error message"));
+    /// ```
+    pub fn take_ok(&mut self) -> Option<T> {
+        replace_with(self, |x| match x {
+            Ok(ok) => (Ok(ok), None),
+            Err(err) => (Err(err), None),
+            Both(ok, err) => (Err(err), Some(ok)),
+        })
+    }
+
+    /// Counts the results of each variant in `results`, in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use resultish::Tally;
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// assert_eq!(
+    ///     Resultish::tally(&x),
+    ///     Tally {
+    ///         ok_only: 2,
+    ///         err_only: 1,
+    ///         both: 1,
+    ///         any_ok: 3,
+    ///         any_err: 2,
+    ///     }
+    /// );
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![];
+    /// assert_eq!(Resultish::tally(&x), Tally::default());
+    /// ```
+    pub fn tally(results: &[Resultish<T, E>]) -> Tally {
+        let mut tally = Tally::default();
+        for result in results {
+            match result {
+                Ok(_) => tally.ok_only += 1,
+                Err(_) => tally.err_only += 1,
+                Both(_, _) => tally.both += 1,
+            }
+        }
+        tally.any_ok = tally.ok_only + tally.both;
+        tally.any_err = tally.err_only + tally.both;
+        tally
+    }
+
+    /// Converts to [`Either`](either::Either), where [`Both`] is mapped to
+    /// [`Left`](either::Either::Left), and the error value is discarded.
+    #[cfg(feature = "either")]
+    pub fn to_either_lenient(self) -> either::Either<T, E> {
+        match self {
+            Ok(ok) => either::Either::Left(ok),
+            Err(err) => either::Either::Right(err),
+            Both(ok, _) => either::Either::Left(ok),
+        }
+    }
+
+    /// Converts to [`Either`](either::Either), where [`Both`] is mapped to
+    /// [`Right`](either::Either::Right), and the success value is discarded.
+    #[cfg(feature = "either")]
+    pub fn to_either_strict(self) -> either::Either<T, E> {
+        match self {
+            Ok(ok) => either::Either::Left(ok),
+            Err(err) => either::Either::Right(err),
+            Both(_, err) => either::Either::Right(err),
+        }
+    }
+
+    /// Convert to [`Result`], with [`Both`] resolved according to `strategy`.
+    ///
+    /// This is useful when the strategy is only known at runtime.
+    /// [`BothStrategy::PreferOk`] is equivalent to [`lenient`](Self::lenient), and
+    /// [`BothStrategy::PreferErr`] is equivalent to [`strict`](Self::strict).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::BothStrategy;
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.to_result(BothStrategy::PreferOk), Result::Ok(3));
+    /// assert_eq!(x.to_result(BothStrategy::PreferErr), Result::Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.to_result(BothStrategy::PreferOk), Result::Err("Some error message"));
+    /// assert_eq!(x.to_result(BothStrategy::PreferErr), Result::Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.to_result(BothStrategy::PreferOk), Result::Ok(3));
+    /// assert_eq!(x.to_result(BothStrategy::PreferErr), Result::Err("Some error message"));
+    ///
+    /// let allow_warnings = false;
+    /// let strategy = match allow_warnings {
+    ///     true => BothStrategy::PreferOk,
+    ///     false => BothStrategy::PreferErr,
+    /// };
+    /// assert_eq!(x.to_result(strategy), Result::Err("Some error message"));
+    /// ```
+    pub fn to_result(self, strategy: BothStrategy) -> Result<T, E> {
+        match (self, strategy) {
+            (Ok(ok), _) => Result::Ok(ok),
+            (Err(err), _) => Result::Err(err),
+            (Both(ok, _), BothStrategy::PreferOk) => Result::Ok(ok),
+            (Both(_, err), BothStrategy::PreferErr) => Result::Err(err),
+        }
+    }
+
+    /// Converts the success value and error value, if any, to strings with their [`Display`]
+    /// implementations.
+    ///
+    /// [`Display`]: fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.to_string_pair(), (Some("3".to_string()), None));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.to_string_pair(), (None, Some("Some error message".to_string())));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(
+    ///     x.to_string_pair(),
+    ///     (Some("3".to_string()), Some("Some error message".to_string())),
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_string_pair(&self) -> (Option<String>, Option<String>)
+    where
+        T: fmt::Display,
+        E: fmt::Display,
+    {
+        let (ok, err) = self.split_ref();
+        (ok.map(ToString::to_string), err.map(ToString::to_string))
+    }
+
+    /// Convert to tuple of the success and error values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.tuple(), (Some(3), None));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.tuple(), (None, Some("Some error message")));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.tuple(), (Some(3), Some("Some error message")));
+    /// ```
+    pub fn tuple(self) -> (Option<T>, Option<E>) {
+        match self {
+            Ok(ok) => (Some(ok), None),
+            Err(err) => (None, Some(err)),
+            Both(ok, err) => (Some(ok), Some(err)),
+        }
+    }
+
+    /// Returns the success and error values if the result is [`Both`], or returns `self` unchanged
+    /// in [`Result::Err`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.try_into_both(), Result::Err(Ok(3)));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.try_into_both(), Result::Err(Err("Some error message")));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.try_into_both(), Result::Ok((3, "Some error message")));
+    /// ```
+    pub fn try_into_both(self) -> Result<(T, E), Self> {
+        match self {
+            Both(ok, err) => Result::Ok((ok, err)),
+            other => Result::Err(other),
+        }
+    }
+
+    /// Maps the success value with the fallible function `f`.
+    ///
+    /// If `f` succeeds, the new success value is kept, along with any existing error value. If `f`
+    /// fails, the success value is demoted into an error: the result is [`Err`] with the error
+    /// returned by `f`, replacing any existing error value. [`Err`] is returned unchanged, without
+    /// calling `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let positive = |x: i32| if x > 0 { Result::Ok(x * 2) } else { Result::Err("Not positive") };
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.try_map(positive), Ok(6));
+    ///
+    /// let x: Resultish<i32, &str> = Ok(-3);
+    /// assert_eq!(x.try_map(positive), Err("Not positive"));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.try_map(positive), Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.try_map(positive), Both(6, "Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(-3, "Some error message");
+    /// assert_eq!(x.try_map(positive), Err("Not positive"));
+    /// ```
+    pub fn try_map<U, F>(self, f: F) -> Resultish<U, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        match self {
+            Ok(ok) => Resultish::from(f(ok)),
+            Err(err) => Err(err),
+            Both(ok, err) => match f(ok) {
+                Result::Ok(ok) => Both(ok, err),
+                Result::Err(new_err) => Err(new_err),
+            },
+        }
+    }
+
+    /// Returns the contained success value, consuming `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`Err`], with a panic message including the error value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.unwrap(), 3);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.unwrap(), 3);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resultish::Resultish::{self, Err};
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// // panics with `called `Resultish::unwrap()` on an `Err` value: "Some error message"`
+    /// x.unwrap();
+    /// ```
+    #[track_caller]
+    pub fn unwrap(self) -> T
+    where
+        E: fmt::Debug,
+    {
+        match self {
+            Ok(ok) => ok,
+            Err(err) => unwrap_failed("called `Resultish::unwrap()` on an `Err` value", &err),
+            Both(ok, _) => ok,
+        }
+    }
+
+    /// Returns the contained success and error values, consuming `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`Ok`] or [`Err`], with a panic message including the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both};
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.unwrap_both(), (3, "Some error message"));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resultish::Resultish::{self, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// // panics with `called `Resultish::unwrap_both()` on an `Ok` value: 3`
+    /// x.unwrap_both();
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resultish::Resultish::{self, Err};
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// // panics with `called `Resultish::unwrap_both()` on an `Err` value: "Some error message"`
+    /// x.unwrap_both();
+    /// ```
+    #[track_caller]
+    pub fn unwrap_both(self) -> (T, E)
+    where
+        T: fmt::Debug,
+        E: fmt::Debug,
+    {
+        match self {
+            Ok(ok) => unwrap_failed("called `Resultish::unwrap_both()` on an `Ok` value", &ok),
+            Err(err) => unwrap_failed("called `Resultish::unwrap_both()` on an `Err` value", &err),
+            Both(ok, err) => (ok, err),
+        }
+    }
+
+    /// Returns the contained error value, consuming `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`Ok`], with a panic message including the success value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err};
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.unwrap_err(), "Some error message");
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.unwrap_err(), "Some error message");
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resultish::Resultish::{self, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// x.unwrap_err(); // panics with `called `Resultish::unwrap_err()` on an `Ok` value: 3`
+    /// ```
+    #[track_caller]
+    pub fn unwrap_err(self) -> E
+    where
+        T: fmt::Debug,
+    {
+        match self {
+            Ok(ok) => unwrap_failed("called `Resultish::unwrap_err()` on an `Ok` value", &ok),
+            Err(err) => err,
+            Both(_, err) => err,
+        }
+    }
+
+    /// Returns the contained success value or a provided default.
+    ///
+    /// The success value of [`Both`] is always preferred over the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.unwrap_or(0), 3);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.unwrap_or(0), 0);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.unwrap_or(0), 3);
+    /// ```
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Ok(ok) => ok,
+            Err(_) => default,
+            Both(ok, _) => ok,
+        }
+    }
+
+    /// Returns the contained success value or a default.
+    ///
+    /// The success value of [`Both`] is always preferred over the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.unwrap_or_default(), 3);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.unwrap_or_default(), 0);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.unwrap_or_default(), 3);
+    /// ```
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Ok(ok) => ok,
+            Err(_) => T::default(),
+            Both(ok, _) => ok,
+        }
+    }
+
+    /// Returns the contained success value or computes it from the error value.
+    ///
+    /// The success value of [`Both`] is always preferred, and `op` is not called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<usize, &str> = Ok(3);
+    /// assert_eq!(x.unwrap_or_else(|err| err.len()), 3);
+    ///
+    /// let x: Resultish<usize, &str> = Err("Some error message");
+    /// assert_eq!(x.unwrap_or_else(|err| err.len()), 18);
+    ///
+    /// let x: Resultish<usize, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.unwrap_or_else(|err| err.len()), 3);
+    /// ```
+    pub fn unwrap_or_else<F>(self, op: F) -> T
+    where
+        F: FnOnce(E) -> T,
+    {
+        match self {
+            Ok(ok) => ok,
+            Err(err) => op(err),
+            Both(ok, _) => ok,
+        }
+    }
+
+    /// Returns the contained success value or a provided default, logging the error value, if
+    /// any, with [`log::warn!`].
+    ///
+    /// The success value of [`Both`] is always preferred over the default, but its error value is
+    /// still logged.
+    #[cfg(feature = "log")]
+    pub fn unwrap_or_log(self, default: T) -> T
+    where
+        E: fmt::Debug,
+    {
+        match self {
+            Ok(ok) => ok,
+            Err(err) => {
+                log::warn!("{:?}", err);
+                default
+            }
+            Both(ok, err) => {
+                log::warn!("{:?}", err);
+                ok
+            }
+        }
+    }
+
+    /// Converts the error value, if any, into an [`anyhow::Error`], wrapped with the context
+    /// returned by `f`. `f` is only called if there is an error value.
+    #[cfg(feature = "anyhow")]
+    pub fn with_context<C, F>(self, f: F) -> Resultish<T, anyhow::Error>
+    where
+        E: Into<anyhow::Error>,
+        C: fmt::Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|err| err.into().context(f()))
+    }
+
+    /// Zips `self` with another `Resultish`.
+    ///
+    /// If both contain a success value, the result contains the pair of success values. If either
+    /// contains an error value, the result contains an error value. If both contain an error value,
+    /// the error value of `self` is kept, and the error value of `other` is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let ok: Resultish<i32, &str> = Ok(3);
+    /// let err: Resultish<i32, &str> = Err("First");
+    /// let both: Resultish<i32, &str> = Both(3, "First");
+    /// let other_ok: Resultish<char, &str> = Ok('a');
+    /// let other_err: Resultish<char, &str> = Err("Second");
+    /// let other_both: Resultish<char, &str> = Both('a', "Second");
+    ///
+    /// assert_eq!(ok.zip(other_ok), Ok((3, 'a')));
+    /// assert_eq!(ok.zip(other_err), Err("Second"));
+    /// assert_eq!(ok.zip(other_both), Both((3, 'a'), "Second"));
+    ///
+    /// assert_eq!(err.zip(other_ok), Err("First"));
+    /// assert_eq!(err.zip(other_err), Err("First"));
+    /// assert_eq!(err.zip(other_both), Err("First"));
+    ///
+    /// assert_eq!(both.zip(other_ok), Both((3, 'a'), "First"));
+    /// assert_eq!(both.zip(other_err), Err("First"));
+    /// assert_eq!(both.zip(other_both), Both((3, 'a'), "First"));
+    /// ```
+    pub fn zip<U>(self, other: Resultish<U, E>) -> Resultish<(T, U), E> {
+        self.zip_with(other, |ok, other_ok| (ok, other_ok))
+    }
+
+    /// Zips `self` with two other `Resultish`s, merging the error values with `merge_err`.
+    ///
+    /// This is like calling [`combine`](Self::combine) twice: the result contains the tuple of
+    /// success values only if all three contain a success value, and all error values are merged
+    /// in order, so that no error value is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let merge = |a: String, b: String| a + &b;
+    ///
+    /// let a: Resultish<i32, String> = Ok(1);
+    /// let b: Resultish<char, String> = Ok('x');
+    /// let c: Resultish<bool, String> = Ok(true);
+    /// assert_eq!(a.zip3(b, c, merge), Ok((1, 'x', true)));
+    ///
+    /// let a: Resultish<i32, String> = Ok(1);
+    /// let b: Resultish<char, String> = Both('x', "b".to_string());
+    /// let c: Resultish<bool, String> = Ok(true);
+    /// assert_eq!(a.zip3(b, c, merge), Both((1, 'x', true), "b".to_string()));
+    ///
+    /// let a: Resultish<i32, String> = Both(1, "a".to_string());
+    /// let b: Resultish<char, String> = Ok('x');
+    /// let c: Resultish<bool, String> = Err("c".to_string());
+    /// assert_eq!(a.zip3(b, c, merge), Err("ac".to_string()));
+    ///
+    /// let a: Resultish<i32, String> = Err("a".to_string());
+    /// let b: Resultish<char, String> = Err("b".to_string());
+    /// let c: Resultish<bool, String> = Err("c".to_string());
+    /// assert_eq!(a.zip3(b, c, merge), Err("abc".to_string()));
+    /// ```
+    pub fn zip3<U, V, FE>(
+        self,
+        b: Resultish<U, E>,
+        c: Resultish<V, E>,
+        mut merge_err: FE,
+    ) -> Resultish<(T, U, V), E>
+    where
+        FE: FnMut(E, E) -> E,
+    {
+        self.combine(b, &mut merge_err)
+            .combine(c, &mut merge_err)
+            .map(|((a, b), c)| (a, b, c))
+    }
+
+    /// Zips `self` with three other `Resultish`s, merging the error values with `merge_err`.
+    ///
+    /// The error values are handled the same way as [`zip3`](Self::zip3).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let merge = |a: String, b: String| a + &b;
+    ///
+    /// let a: Resultish<i32, String> = Ok(1);
+    /// let b: Resultish<char, String> = Ok('x');
+    /// let c: Resultish<bool, String> = Ok(true);
+    /// let d: Resultish<&str, String> = Ok("d");
+    /// assert_eq!(a.zip4(b, c, d, merge), Ok((1, 'x', true, "d")));
+    ///
+    /// let a: Resultish<i32, String> = Both(1, "a".to_string());
+    /// let b: Resultish<char, String> = Ok('x');
+    /// let c: Resultish<bool, String> = Both(true, "c".to_string());
+    /// let d: Resultish<&str, String> = Ok("d");
+    /// assert_eq!(a.zip4(b, c, d, merge), Both((1, 'x', true, "d"), "ac".to_string()));
+    ///
+    /// let a: Resultish<i32, String> = Err("a".to_string());
+    /// let b: Resultish<char, String> = Err("b".to_string());
+    /// let c: Resultish<bool, String> = Err("c".to_string());
+    /// let d: Resultish<&str, String> = Err("d".to_string());
+    /// assert_eq!(a.zip4(b, c, d, merge), Err("abcd".to_string()));
+    /// ```
+    pub fn zip4<U, V, W, FE>(
+        self,
+        b: Resultish<U, E>,
+        c: Resultish<V, E>,
+        d: Resultish<W, E>,
+        mut merge_err: FE,
+    ) -> Resultish<(T, U, V, W), E>
+    where
+        FE: FnMut(E, E) -> E,
+    {
+        self.combine(b, &mut merge_err)
+            .combine(c, &mut merge_err)
+            .combine(d, &mut merge_err)
+            .map(|(((a, b), c), d)| (a, b, c, d))
+    }
+
+    /// Zips the error values of `self` and another `Resultish`.
+    ///
+    /// This mirrors [`zip`](Self::zip) with the roles of the success and error values swapped: if
+    /// both contain an error value, the result contains the pair of error values. If either
+    /// contains a success value, the result contains a success value. If both contain a success
+    /// value, the success value of `self` is kept, and the success value of `other` is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let ok: Resultish<i32, &str> = Ok(3);
+    /// let err: Resultish<i32, &str> = Err("First");
+    /// let both: Resultish<i32, &str> = Both(3, "First");
+    /// let other_ok: Resultish<i32, char> = Ok(4);
+    /// let other_err: Resultish<i32, char> = Err('x');
+    /// let other_both: Resultish<i32, char> = Both(4, 'x');
+    ///
+    /// assert_eq!(ok.zip_err(other_ok), Ok(3));
+    /// assert_eq!(ok.zip_err(other_err), Ok(3));
+    /// assert_eq!(ok.zip_err(other_both), Ok(3));
+    ///
+    /// assert_eq!(err.zip_err(other_ok), Ok(4));
+    /// assert_eq!(err.zip_err(other_err), Err(("First", 'x')));
+    /// assert_eq!(err.zip_err(other_both), Both(4, ("First", 'x')));
+    ///
+    /// assert_eq!(both.zip_err(other_ok), Ok(3));
+    /// assert_eq!(both.zip_err(other_err), Both(3, ("First", 'x')));
+    /// assert_eq!(both.zip_err(other_both), Both(3, ("First", 'x')));
+    /// ```
+    pub fn zip_err<F>(self, other: Resultish<T, F>) -> Resultish<T, (E, F)> {
+        self.swap().zip(other.swap()).swap()
+    }
+
+    /// Zips the error values of `self` and another `Resultish`, merging the success values with
+    /// `merge_ok`.
+    ///
+    /// This mirrors [`combine`](Self::combine) with the roles of the success and error values
+    /// swapped, so no success value is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let ok: Resultish<i32, &str> = Ok(3);
+    /// let err: Resultish<i32, &str> = Err("First");
+    /// let both: Resultish<i32, &str> = Both(3, "First");
+    /// let other_ok: Resultish<i32, char> = Ok(4);
+    /// let other_err: Resultish<i32, char> = Err('x');
+    /// let other_both: Resultish<i32, char> = Both(4, 'x');
+    /// let add = |a, b| a + b;
+    ///
+    /// assert_eq!(ok.zip_err_with(other_ok, add), Ok(7));
+    /// assert_eq!(ok.zip_err_with(other_err, add), Ok(3));
+    /// assert_eq!(ok.zip_err_with(other_both, add), Ok(7));
+    ///
+    /// assert_eq!(err.zip_err_with(other_ok, add), Ok(4));
+    /// assert_eq!(err.zip_err_with(other_err, add), Err(("First", 'x')));
+    /// assert_eq!(err.zip_err_with(other_both, add), Both(4, ("First", 'x')));
+    ///
+    /// assert_eq!(both.zip_err_with(other_ok, add), Ok(7));
+    /// assert_eq!(both.zip_err_with(other_err, add), Both(3, ("First", 'x')));
+    /// assert_eq!(both.zip_err_with(other_both, add), Both(7, ("First", 'x')));
+    /// ```
+    pub fn zip_err_with<F, FT>(self, other: Resultish<T, F>, merge_ok: FT) -> Resultish<T, (E, F)>
+    where
+        FT: FnOnce(T, T) -> T,
+    {
+        self.swap().combine(other.swap(), merge_ok).swap()
+    }
+
+    /// Zips `self` with another `Resultish`, using `f` to combine the success values.
+    ///
+    /// The error values are handled the same way as [`zip`](Self::zip).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "First");
+    /// let y: Resultish<i32, &str> = Ok(4);
+    /// assert_eq!(x.zip_with(y, |x, y| x + y), Both(7, "First"));
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// let y: Resultish<i32, &str> = Err("Second");
+    /// assert_eq!(x.zip_with(y, |x, y| x + y), Err("Second"));
+    /// ```
+    pub fn zip_with<U, R, F>(self, other: Resultish<U, E>, f: F) -> Resultish<R, E>
+    where
+        F: FnOnce(T, U) -> R,
+    {
+        match (self, other) {
+            (Ok(ok), Ok(other_ok)) => Ok(f(ok, other_ok)),
+            (Ok(_), Err(other_err)) => Err(other_err),
+            (Ok(ok), Both(other_ok, other_err)) => Both(f(ok, other_ok), other_err),
+            (Err(err), _) => Err(err),
+            (Both(ok, err), Ok(other_ok)) => Both(f(ok, other_ok), err),
+            (Both(_, err), Err(_)) => Err(err),
+            (Both(ok, err), Both(other_ok, _)) => Both(f(ok, other_ok), err),
+        }
+    }
+}
+
+impl<T, E> Resultish<&T, E> {
+    /// Maps a `Resultish<&T, E>` to a `Resultish<T, E>` by cloning the contents of the success
+    /// value.
+    pub fn cloned(self) -> Resultish<T, E>
+    where
+        T: Clone,
+    {
+        match self {
+            Ok(ok) => Ok(ok.clone()),
+            Err(err) => Err(err),
+            Both(ok, err) => Both(ok.clone(), err),
+        }
+    }
+
+    /// Maps a `Resultish<&T, E>` to a `Resultish<T, E>` by copying the contents of the success
+    /// value.
+    pub fn copied(self) -> Resultish<T, E>
+    where
+        T: Copy,
+    {
+        match self {
+            Ok(ok) => Ok(*ok),
+            Err(err) => Err(err),
+            Both(ok, err) => Both(*ok, err),
+        }
+    }
+}
+
+impl<T, E> Resultish<&mut T, E> {
+    /// Maps a `Resultish<&mut T, E>` to a `Resultish<T, E>` by cloning the contents of the success
+    /// value.
+    pub fn cloned(self) -> Resultish<T, E>
+    where
+        T: Clone,
+    {
+        match self {
+            Ok(ok) => Ok(ok.clone()),
+            Err(err) => Err(err),
+            Both(ok, err) => Both(ok.clone(), err),
+        }
+    }
+
+    /// Maps a `Resultish<&mut T, E>` to a `Resultish<T, E>` by copying the contents of the success
+    /// value.
+    pub fn copied(self) -> Resultish<T, E>
+    where
+        T: Copy,
+    {
+        match self {
+            Ok(ok) => Ok(*ok),
+            Err(err) => Err(err),
+            Both(ok, err) => Both(*ok, err),
+        }
+    }
+}
+
+impl<T, E> Resultish<&T, &E> {
+    /// Maps a `Resultish<&T, &E>` to a `Resultish<T, E>` by cloning the contents of the success
+    /// and error values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<Vec<i32>, String> = Ok(vec![3]);
+    /// assert_eq!(x.as_ref().cloned_both(), x);
+    ///
+    /// let x: Resultish<Vec<i32>, String> = Err("Some error message".to_string());
+    /// assert_eq!(x.as_ref().cloned_both(), x);
+    ///
+    /// let x: Resultish<Vec<i32>, String> = Both(vec![3], "Some error message".to_string());
+    /// assert_eq!(x.as_ref().cloned_both(), x);
+    /// ```
+    pub fn cloned_both(self) -> Resultish<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        match self {
+            Ok(ok) => Ok(ok.clone()),
+            Err(err) => Err(err.clone()),
+            Both(ok, err) => Both(ok.clone(), err.clone()),
+        }
+    }
+
+    /// Maps a `Resultish<&T, &E>` to a `Resultish<T, E>` by copying the contents of the success
+    /// and error values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.as_ref().copied_both(), x);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.as_ref().copied_both(), x);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.as_ref().copied_both(), x);
+    /// ```
+    pub fn copied_both(self) -> Resultish<T, E>
+    where
+        T: Copy,
+        E: Copy,
+    {
+        match self {
+            Ok(ok) => Ok(*ok),
+            Err(err) => Err(*err),
+            Both(ok, err) => Both(*ok, *err),
+        }
+    }
+}
+
+impl<T, E> Resultish<&mut T, &mut E> {
+    /// Maps a `Resultish<&mut T, &mut E>` to a `Resultish<T, E>` by cloning the contents of the
+    /// success and error values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<Vec<i32>, String> = Ok(vec![3]);
+    /// let y = x.as_mut().cloned_both();
+    /// assert_eq!(y, x);
+    ///
+    /// let mut x: Resultish<Vec<i32>, String> = Err("Some error message".to_string());
+    /// let y = x.as_mut().cloned_both();
+    /// assert_eq!(y, x);
+    ///
+    /// let mut x: Resultish<Vec<i32>, String> = Both(vec![3], "Some error message".to_string());
+    /// let y = x.as_mut().cloned_both();
+    /// assert_eq!(y, x);
+    /// ```
+    pub fn cloned_both(self) -> Resultish<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        match self {
+            Ok(ok) => Ok(ok.clone()),
+            Err(err) => Err(err.clone()),
+            Both(ok, err) => Both(ok.clone(), err.clone()),
+        }
+    }
+
+    /// Maps a `Resultish<&mut T, &mut E>` to a `Resultish<T, E>` by copying the contents of the
+    /// success and error values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<i32, &str> = Ok(3);
+    /// let y = x.as_mut().copied_both();
+    /// assert_eq!(y, x);
+    ///
+    /// let mut x: Resultish<i32, &str> = Err("Some error message");
+    /// let y = x.as_mut().copied_both();
+    /// assert_eq!(y, x);
+    ///
+    /// let mut x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// let y = x.as_mut().copied_both();
+    /// assert_eq!(y, x);
+    /// ```
+    pub fn copied_both(self) -> Resultish<T, E>
+    where
+        T: Copy,
+        E: Copy,
+    {
+        match self {
+            Ok(ok) => Ok(*ok),
+            Err(err) => Err(*err),
+            Both(ok, err) => Both(*ok, *err),
+        }
+    }
+}
+
+impl<T, E> Resultish<Option<T>, E> {
+    /// Transposes a `Resultish` of an [`Option`] into an [`Option`] of a `Resultish`.
+    ///
+    /// [`Ok(None)`](Ok) is mapped to [`None`]. [`Both(None, e)`](Both) has no success value left,
+    /// so it collapses to [`Some(Err(e))`](Err), keeping the error value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<Option<i32>, &str> = Ok(Some(3));
+    /// assert_eq!(x.transpose(), Some(Ok(3)));
+    ///
+    /// let x: Resultish<Option<i32>, &str> = Ok(None);
+    /// assert_eq!(x.transpose(), None);
+    ///
+    /// let x: Resultish<Option<i32>, &str> = Err("Some error message");
+    /// assert_eq!(x.transpose(), Some(Err("Some error message")));
+    ///
+    /// let x: Resultish<Option<i32>, &str> = Both(Some(3), "Some error message");
+    /// assert_eq!(x.transpose(), Some(Both(3, "Some error message")));
+    ///
+    /// let x: Resultish<Option<i32>, &str> = Both(None, "Some error message");
+    /// assert_eq!(x.transpose(), Some(Err("Some error message")));
+    /// ```
+    pub fn transpose(self) -> Option<Resultish<T, E>> {
+        match self {
+            Ok(Some(ok)) => Some(Ok(ok)),
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+            Both(Some(ok), err) => Some(Both(ok, err)),
+            Both(None, err) => Some(Err(err)),
+        }
+    }
+}
+
+impl<T, E> Resultish<T, Option<E>> {
+    /// Transposes a `Resultish` with an [`Option`] error value into an [`Option`] of a
+    /// `Resultish`.
+    ///
+    /// [`Err(None)`](Err) has no value left at all, so it collapses to [`None`].
+    /// [`Both(t, None)`](Both) collapses to [`Some(Ok(t))`](Ok), keeping the success value.
+    ///
+    /// This is named `transpose_err` rather than `transpose`, as `Resultish<Option<T>, Option<E>>`
+    /// would otherwise have two methods called [`transpose`](Resultish::transpose).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, Option<&str>> = Ok(3);
+    /// assert_eq!(x.transpose_err(), Some(Ok(3)));
+    ///
+    /// let x: Resultish<i32, Option<&str>> = Err(None);
+    /// assert_eq!(x.transpose_err(), None);
+    ///
+    /// let x: Resultish<i32, Option<&str>> = Err(Some("Some error message"));
+    /// assert_eq!(x.transpose_err(), Some(Err("Some error message")));
+    ///
+    /// let x: Resultish<i32, Option<&str>> = Both(3, None);
+    /// assert_eq!(x.transpose_err(), Some(Ok(3)));
+    ///
+    /// let x: Resultish<i32, Option<&str>> = Both(3, Some("Some error message"));
+    /// assert_eq!(x.transpose_err(), Some(Both(3, "Some error message")));
+    /// ```
+    pub fn transpose_err(self) -> Option<Resultish<T, E>> {
+        match self {
+            Ok(ok) => Some(Ok(ok)),
+            Err(Some(err)) => Some(Err(err)),
+            Err(None) => None,
+            Both(ok, Some(err)) => Some(Both(ok, err)),
+            Both(ok, None) => Some(Ok(ok)),
+        }
+    }
+}
+
+impl<T, E> Resultish<Resultish<T, E>, E> {
+    /// Converts from `Resultish<Resultish<T, E>, E>` to `Resultish<T, E>`.
+    ///
+    /// If both the inner and outer `Resultish` contain an error value, the inner error value is
+    /// kept, and the outer error value is discarded. This is equivalent to
+    /// [`and_then`](Resultish::and_then)`(|x| x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<Resultish<i32, &str>, &str> = Ok(Ok(3));
+    /// assert_eq!(x.flatten(), Ok(3));
+    ///
+    /// let x: Resultish<Resultish<i32, &str>, &str> = Ok(Err("Inner"));
+    /// assert_eq!(x.flatten(), Err("Inner"));
+    ///
+    /// let x: Resultish<Resultish<i32, &str>, &str> = Ok(Both(3, "Inner"));
+    /// assert_eq!(x.flatten(), Both(3, "Inner"));
+    ///
+    /// let x: Resultish<Resultish<i32, &str>, &str> = Err("Outer");
+    /// assert_eq!(x.flatten(), Err("Outer"));
+    ///
+    /// let x: Resultish<Resultish<i32, &str>, &str> = Both(Ok(3), "Outer");
+    /// assert_eq!(x.flatten(), Both(3, "Outer"));
+    ///
+    /// let x: Resultish<Resultish<i32, &str>, &str> = Both(Err("Inner"), "Outer");
+    /// assert_eq!(x.flatten(), Err("Inner"));
+    ///
+    /// let x: Resultish<Resultish<i32, &str>, &str> = Both(Both(3, "Inner"), "Outer");
+    /// assert_eq!(x.flatten(), Both(3, "Inner"));
+    /// ```
+    pub fn flatten(self) -> Resultish<T, E> {
+        match self {
+            Ok(inner) => inner,
+            Err(err) => Err(err),
+            Both(Ok(ok), err) => Both(ok, err),
+            Both(Err(err), _) => Err(err),
+            Both(Both(ok, err), _) => Both(ok, err),
+        }
+    }
+
+    /// Converts from `Resultish<Resultish<T, E>, E>` to `Resultish<T, E>`, merging the error
+    /// values with `merge`.
+    ///
+    /// Unlike [`flatten`](Self::flatten), if both the inner and outer `Resultish` contain an error
+    /// value, neither is discarded: they are combined with `merge`, which is passed the outer
+    /// error value first. `merge` is only called if both contain an error value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let merge = |outer: String, inner: String| outer + ", " + &inner;
+    ///
+    /// let x: Resultish<Resultish<i32, String>, String> = Ok(Ok(3));
+    /// assert_eq!(x.join_with(merge), Ok(3));
+    ///
+    /// let x: Resultish<Resultish<i32, String>, String> = Ok(Err("Inner".to_string()));
+    /// assert_eq!(x.join_with(merge), Err("Inner".to_string()));
+    ///
+    /// let x: Resultish<Resultish<i32, String>, String> = Ok(Both(3, "Inner".to_string()));
+    /// assert_eq!(x.join_with(merge), Both(3, "Inner".to_string()));
+    ///
+    /// let x: Resultish<Resultish<i32, String>, String> = Err("Outer".to_string());
+    /// assert_eq!(x.join_with(merge), Err("Outer".to_string()));
+    ///
+    /// let x: Resultish<Resultish<i32, String>, String> = Both(Ok(3), "Outer".to_string());
+    /// assert_eq!(x.join_with(merge), Both(3, "Outer".to_string()));
+    ///
+    /// let x: Resultish<Resultish<i32, String>, String> =
+    ///     Both(Err("Inner".to_string()), "Outer".to_string());
+    /// assert_eq!(x.join_with(merge), Err("Outer, Inner".to_string()));
+    ///
+    /// let x: Resultish<Resultish<i32, String>, String> =
+    ///     Both(Both(3, "Inner".to_string()), "Outer".to_string());
+    /// assert_eq!(x.join_with(merge), Both(3, "Outer, Inner".to_string()));
+    /// ```
+    pub fn join_with<F>(self, merge: F) -> Resultish<T, E>
+    where
+        F: FnOnce(E, E) -> E,
+    {
+        match self {
+            Ok(inner) => inner,
+            Err(err) => Err(err),
+            Both(Ok(ok), err) => Both(ok, err),
+            Both(Err(inner_err), err) => Err(merge(err, inner_err)),
+            Both(Both(ok, inner_err), err) => Both(ok, merge(err, inner_err)),
+        }
+    }
+}
+
+impl<U, E2, E> Resultish<Result<U, E2>, E>
+where
+    E2: Into<E>,
+{
+    /// Converts from `Resultish<Result<U, E2>, E>` to `Resultish<U, E>`, moving the error value of
+    /// the inner [`Result`] into the error value of the `Resultish`.
+    ///
+    /// This is like [`flatten`](Resultish::flatten): for [`Both`] with an inner [`Result::Err`],
+    /// the inner error value is kept, and the outer error value is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<Result<i32, &str>, String> = Ok(Result::Ok(3));
+    /// assert_eq!(x.transpose_result(), Ok(3));
+    ///
+    /// let x: Resultish<Result<i32, &str>, String> = Ok(Result::Err("Inner"));
+    /// assert_eq!(x.transpose_result(), Err("Inner".to_string()));
+    ///
+    /// let x: Resultish<Result<i32, &str>, String> = Err("Outer".to_string());
+    /// assert_eq!(x.transpose_result(), Err("Outer".to_string()));
+    ///
+    /// let x: Resultish<Result<i32, &str>, String> = Both(Result::Ok(3), "Outer".to_string());
+    /// assert_eq!(x.transpose_result(), Both(3, "Outer".to_string()));
+    ///
+    /// let x: Resultish<Result<i32, &str>, String> = Both(Result::Err("Inner"), "Outer".to_string());
+    /// assert_eq!(x.transpose_result(), Err("Inner".to_string()));
+    /// ```
+    pub fn transpose_result(self) -> Resultish<U, E> {
+        match self {
+            Ok(Result::Ok(ok)) => Ok(ok),
+            Ok(Result::Err(err)) => Err(err.into()),
+            Err(err) => Err(err),
+            Both(Result::Ok(ok), err) => Both(ok, err),
+            Both(Result::Err(err), _) => Err(err.into()),
+        }
+    }
+}
+
+impl<A, B, E> Resultish<(A, B), E> {
+    /// Unzips a `Resultish` of a pair into a pair of `Resultish`s.
+    ///
+    /// The error value, if any, is cloned, so that it is contained in both results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<(i32, char), &str> = Ok((3, 'a'));
+    /// assert_eq!(x.unzip(), (Ok(3), Ok('a')));
+    ///
+    /// let x: Resultish<(i32, char), &str> = Err("Some error message");
+    /// assert_eq!(x.unzip(), (Err("Some error message"), Err("Some error message")));
+    ///
+    /// let x: Resultish<(i32, char), &str> = Both((3, 'a'), "Some error message");
+    /// assert_eq!(x.unzip(), (Both(3, "Some error message"), Both('a', "Some error message")));
+    /// ```
+    pub fn unzip(self) -> (Resultish<A, E>, Resultish<B, E>)
+    where
+        E: Clone,
+    {
+        match self {
+            Ok((a, b)) => (Ok(a), Ok(b)),
+            Err(err) => (Err(err.clone()), Err(err)),
+            Both((a, b), err) => (Both(a, err.clone()), Both(b, err)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Resultish<T, Box<dyn Any + Send>> {
+    /// Calls `f`, catching any panic with [`catch_unwind`](panic::catch_unwind).
+    ///
+    /// Returns [`Ok`] with the return value of `f`, or [`Err`] with the unwind payload if `f`
+    /// panics. The payload is usually a `&'static str` or a [`String`], depending on how the panic
+    /// was raised. [`Both`] is never produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish;
+    ///
+    /// let x = Resultish::catch(|| 3);
+    /// assert_eq!(x.ok(), Some(3));
+    ///
+    /// let x = Resultish::<i32, _>::catch(|| panic!("Some error message"));
+    /// assert_eq!(x.err().unwrap().downcast_ref::<&str>(), Some(&"Some error message"));
+    /// ```
+    pub fn catch<F>(f: F) -> Self
+    where
+        F: FnOnce() -> T + panic::UnwindSafe,
+    {
+        Resultish::from(panic::catch_unwind(f))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, E> Resultish<Vec<T>, Vec<E>> {
+    /// Pushes the success value and error value of `item`, if any, onto the success and error
+    /// vectors.
+    ///
+    /// If `self` has no vector for a value being pushed, a new one is created, changing the
+    /// variant to [`Both`]. Existing vectors are kept, even if they are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<Vec<i32>, Vec<&str>> = Ok(vec![]);
+    /// x.push(Ok(1));
+    /// assert_eq!(x, Ok(vec![1]));
+    /// x.push(Both(2, "First"));
+    /// assert_eq!(x, Both(vec![1, 2], vec!["First"]));
+    /// x.push(Err("Second"));
+    /// assert_eq!(x, Both(vec![1, 2], vec!["First", "Second"]));
+    ///
+    /// let mut x: Resultish<Vec<i32>, Vec<&str>> = Err(vec!["First"]);
+    /// x.push(Err("Second"));
+    /// assert_eq!(x, Err(vec!["First", "Second"]));
+    /// x.push(Ok(1));
+    /// assert_eq!(x, Both(vec![1], vec!["First", "Second"]));
+    /// ```
+    pub fn push(&mut self, item: Resultish<T, E>) {
+        match item {
+            Ok(ok) => match self {
+                Ok(oks) => oks.push(ok),
+                Err(errs) => {
+                    let errs = mem::take(errs);
+                    *self = Both(alloc::vec![ok], errs);
+                }
+                Both(oks, _) => oks.push(ok),
+            },
+            Err(err) => match self {
+                Ok(oks) => {
+                    let oks = mem::take(oks);
+                    *self = Both(oks, alloc::vec![err]);
+                }
+                Err(errs) => errs.push(err),
+                Both(_, errs) => errs.push(err),
+            },
+            Both(ok, err) => {
+                self.push(Ok(ok));
+                self.push(Err(err));
+            }
+        }
+    }
+
+    /// Validates every item with `f`, collecting all success values and all error values instead
+    /// of stopping at the first error.
+    ///
+    /// The result is [`Ok`] if there are no error values, [`Err`] if there are error values but
+    /// no success values, and [`Both`] otherwise. An empty iterator results in [`Ok`] with an empty
+    /// vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let parse = |s: &str| s.parse::<i32>().map_err(|_| format!("Invalid number: {s}"));
+    ///
+    /// let x = Resultish::validate(["1", "2"], parse);
+    /// assert_eq!(x, Ok(vec![1, 2]));
+    ///
+    /// let x = Resultish::validate(["1", "a", "2", "b"], parse);
+    /// assert_eq!(
+    ///     x,
+    ///     Both(
+    ///         vec![1, 2],
+    ///         vec!["Invalid number: a".to_string(), "Invalid number: b".to_string()]
+    ///     )
+    /// );
+    ///
+    /// let x = Resultish::validate(["a"], parse);
+    /// assert_eq!(x, Err(vec!["Invalid number: a".to_string()]));
+    /// ```
+    pub fn validate<I, F>(iter: I, mut f: F) -> Self
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Result<T, E>,
+    {
+        iter.into_iter()
+            .map(|item| Resultish::from(f(item)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B, C> Resultish<Cow<'_, B>, Cow<'_, C>>
+where
+    B: ToOwned + ?Sized,
+    C: ToOwned + ?Sized,
+{
+    /// Converts any borrowed success and error values into owned values, cloning them if
+    /// necessary, so that the result no longer borrows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use std::borrow::Cow;
+    ///
+    /// let x: Resultish<Cow<str>, Cow<str>> = Ok(Cow::Borrowed("Some value"));
+    /// assert_eq!(x.into_owned(), Ok("Some value".to_string()));
+    ///
+    /// let x: Resultish<Cow<str>, Cow<str>> = Err(Cow::Owned("Some error message".to_string()));
+    /// assert_eq!(x.into_owned(), Err("Some error message".to_string()));
+    ///
+    /// let x: Resultish<Cow<str>, Cow<str>> =
+    ///     Both(Cow::Borrowed("Some value"), Cow::Borrowed("Some error message"));
+    /// assert_eq!(
+    ///     x.into_owned(),
+    ///     Both("Some value".to_string(), "Some error message".to_string())
+    /// );
+    /// ```
+    pub fn into_owned(self) -> Resultish<B::Owned, C::Owned> {
+        match self {
+            Ok(ok) => Ok(ok.into_owned()),
+            Err(err) => Err(err.into_owned()),
+            Both(ok, err) => Both(ok.into_owned(), err.into_owned()),
+        }
+    }
+}
+
+/// The default is [`Ok`] with the default success value, as the success-only case is the neutral
+/// state of a `Resultish`.
+impl<T, E> Default for Resultish<T, E>
+where
+    T: Default,
+{
+    /// Returns [`Ok`] with the default success value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Ok};
+    ///
+    /// assert_eq!(Resultish::<i32, String>::default(), Ok(0));
+    /// ```
+    fn default() -> Self {
+        Ok(T::default())
+    }
+}
+
+/// Formats the contained values: [`Ok`] and [`Err`] are formatted as their value alone, and
+/// [`Both`] is formatted as `{ok} (with error: {err})`.
+impl<T, E> fmt::Display for Resultish<T, E>
+where
+    T: fmt::Display,
+    E: fmt::Display,
+{
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.to_string(), "3");
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.to_string(), "Some error message");
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.to_string(), "3 (with error: Some error message)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ok(ok) => write!(f, "{ok}"),
+            Err(err) => write!(f, "{err}"),
+            Both(ok, err) => write!(f, "{ok} (with error: {err})"),
+        }
+    }
+}
+
+/// Results are ordered by their success values first, and then by their error values, where a
+/// missing value is less than any present value.
+///
+/// This means that [`Err`] is less than [`Ok`] and [`Both`], and that `Ok(a)` is less than
+/// `Both(a, e)`. Equivalently, results are ordered as their [`split_ref`](Resultish::split_ref)
+/// tuples. This ordering is stable, and will not change between versions.
+///
+/// # Examples
+///
+/// ```
+/// use resultish::Resultish::{self, Both, Err, Ok};
+///
+/// let ok: Resultish<i32, i32> = Ok(3);
+/// let err: Resultish<i32, i32> = Err(1);
+/// let both: Resultish<i32, i32> = Both(3, 1);
+///
+/// assert!(err < ok);
+/// assert!(err < both);
+/// assert!(ok < both);
+///
+/// assert!(Ok::<i32, i32>(2) < Ok(3));
+/// assert!(Err::<i32, i32>(1) < Err(2));
+/// assert!(Both::<i32, i32>(2, 9) < Ok(3));
+/// assert!(Both::<i32, i32>(3, 1) < Both(3, 2));
+/// assert!(Err::<i32, i32>(9) < Both(0, 0));
+/// ```
+impl<T, E> PartialOrd for Resultish<T, E>
+where
+    T: PartialOrd,
+    E: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.split_ref().partial_cmp(&other.split_ref())
+    }
+}
+
+/// See the [`PartialOrd`] impl for how results are ordered.
+impl<T, E> Ord for Resultish<T, E>
+where
+    T: Ord,
+    E: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.split_ref().cmp(&other.split_ref())
+    }
+}
+
+/// `a & b` is equivalent to [`a.and(b)`](Resultish::and). [`Both`] is treated leniently: as it
+/// contains a success value, `b` is returned, and the error value of `a` is discarded.
+///
+/// # Examples
+///
+/// ```
+/// use resultish::Resultish::{self, Both, Err, Ok};
+///
+/// let ok: Resultish<i32, &str> = Ok(3);
+/// let err: Resultish<i32, &str> = Err("First");
+/// let both: Resultish<i32, &str> = Both(3, "First");
+/// let other_ok: Resultish<char, &str> = Ok('a');
+/// let other_err: Resultish<char, &str> = Err("Second");
+/// let other_both: Resultish<char, &str> = Both('a', "Second");
+///
+/// assert_eq!(ok & other_ok, Ok('a'));
+/// assert_eq!(ok & other_err, Err("Second"));
+/// assert_eq!(ok & other_both, Both('a', "Second"));
+///
+/// assert_eq!(err & other_ok, Err("First"));
+/// assert_eq!(err & other_err, Err("First"));
+/// assert_eq!(err & other_both, Err("First"));
+///
+/// assert_eq!(both & other_ok, Ok('a'));
+/// assert_eq!(both & other_err, Err("Second"));
+/// assert_eq!(both & other_both, Both('a', "Second"));
+/// ```
+impl<T, U, E> ops::BitAnd<Resultish<U, E>> for Resultish<T, E> {
+    type Output = Resultish<U, E>;
+
+    fn bitand(self, rhs: Resultish<U, E>) -> Resultish<U, E> {
+        self.and(rhs)
+    }
+}
+
+/// `a | b` is equivalent to [`a.or(b)`](Resultish::or). [`Both`] is treated leniently: as it
+/// contains a success value, [`Ok`] is returned with the success value of `a`, and the error value
+/// of `a` is discarded.
+///
+/// # Examples
+///
+/// ```
+/// use resultish::Resultish::{self, Both, Err, Ok};
+///
+/// let ok: Resultish<i32, &str> = Ok(3);
+/// let err: Resultish<i32, &str> = Err("First");
+/// let both: Resultish<i32, &str> = Both(3, "First");
+/// let other_ok: Resultish<i32, char> = Ok(4);
+/// let other_err: Resultish<i32, char> = Err('b');
+/// let other_both: Resultish<i32, char> = Both(4, 'b');
+///
+/// assert_eq!(ok | other_ok, Ok(3));
+/// assert_eq!(ok | other_err, Ok(3));
+/// assert_eq!(ok | other_both, Ok(3));
+///
+/// assert_eq!(err | other_ok, Ok(4));
+/// assert_eq!(err | other_err, Err('b'));
+/// assert_eq!(err | other_both, Both(4, 'b'));
+///
+/// assert_eq!(both | other_ok, Ok(3));
+/// assert_eq!(both | other_err, Ok(3));
+/// assert_eq!(both | other_both, Ok(3));
+/// ```
+impl<T, E, F> ops::BitOr<Resultish<T, F>> for Resultish<T, E> {
+    type Output = Resultish<T, F>;
+
+    fn bitor(self, rhs: Resultish<T, F>) -> Resultish<T, F> {
+        self.or(rhs)
+    }
+}
+
+/// Generates each of [`Ok`], [`Err`], and [`Both`] with equal probability.
+#[cfg(feature = "arbitrary")]
+impl<'a, T, E> arbitrary::Arbitrary<'a> for Resultish<T, E>
+where
+    T: arbitrary::Arbitrary<'a>,
+    E: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Result::Ok(match u.int_in_range(0..=2u8)? {
+            0 => Ok(T::arbitrary(u)?),
+            1 => Err(E::arbitrary(u)?),
+            _ => Both(T::arbitrary(u)?, E::arbitrary(u)?),
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        use arbitrary::size_hint;
+
+        size_hint::and(
+            <u8 as arbitrary::Arbitrary>::size_hint(depth),
+            size_hint::or(
+                size_hint::or(T::size_hint(depth), E::size_hint(depth)),
+                size_hint::and(T::size_hint(depth), E::size_hint(depth)),
+            ),
+        )
+    }
+}
+
+/// Generates each of [`Ok`], [`Err`], and [`Both`] with equal probability. [`Both`] is shrunk to
+/// [`Ok`] and [`Err`] first, and then by shrinking each of its values.
+#[cfg(feature = "quickcheck")]
+impl<T, E> quickcheck::Arbitrary for Resultish<T, E>
+where
+    T: quickcheck::Arbitrary,
+    E: quickcheck::Arbitrary,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        match g.choose(&[0, 1, 2]) {
+            Some(0) => Ok(T::arbitrary(g)),
+            Some(1) => Err(E::arbitrary(g)),
+            _ => Both(T::arbitrary(g), E::arbitrary(g)),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Ok(ok) => Box::new(ok.shrink().map(Ok)),
+            Err(err) => Box::new(err.shrink().map(Err)),
+            Both(ok, err) => {
+                let (shrink_ok, shrink_err) = (ok.clone(), err.clone());
+                Box::new(
+                    [Ok(ok.clone()), Err(err.clone())]
+                        .into_iter()
+                        .chain(ok.shrink().map(move |ok| Both(ok, shrink_err.clone())))
+                        .chain(err.shrink().map(move |err| Both(shrink_ok.clone(), err))),
+                )
+            }
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for Resultish<T, E> {
+    fn from(value: Result<T, E>) -> Self {
+        match value {
+            Result::Ok(ok) => Ok(ok),
+            Result::Err(err) => Err(err),
+        }
+    }
+}
+
+impl<T, E> From<Resultish<T, E>> for Result<T, E> {
+    /// Converts strictly, like [`Resultish::strict`]: [`Both`] is mapped to [`Result::Err`], and
+    /// the success value is discarded. Use [`Resultish::lenient`] to keep the success value
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(Result::from(x), Result::Ok(3));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(Result::from(x), Result::Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(Result::from(x), Result::Err("Some error message"));
+    /// let y: Result<i32, &str> = x.into();
+    /// assert_eq!(y, Result::Err("Some error message"));
+    /// ```
+    fn from(value: Resultish<T, E>) -> Self {
+        value.strict()
+    }
+}
+
+#[cfg(feature = "either")]
+impl<T, E> From<either::Either<T, E>> for Resultish<T, E> {
+    /// Maps [`Left`](either::Either::Left) to [`Ok`], and [`Right`](either::Either::Right) to
+    /// [`Err`]. [`Both`] is never produced.
+    fn from(value: either::Either<T, E>) -> Self {
+        match value {
+            either::Either::Left(ok) => Ok(ok),
+            either::Either::Right(err) => Err(err),
+        }
+    }
+}
+
+/// `?` uses strict semantics: [`Err`] and [`Both`] short-circuit with the error value, and the
+/// success value of [`Both`] is discarded.
+#[cfg(feature = "nightly")]
+impl<T, E> ops::Try for Resultish<T, E> {
+    type Output = T;
+    type Residual = Result<convert::Infallible, E>;
+
+    fn from_output(output: T) -> Self {
+        Ok(output)
+    }
+
+    fn branch(self) -> ops::ControlFlow<Self::Residual, T> {
+        match self {
+            Ok(ok) => ops::ControlFlow::Continue(ok),
+            Err(err) => ops::ControlFlow::Break(Result::Err(err)),
+            Both(_, err) => ops::ControlFlow::Break(Result::Err(err)),
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, E, F> ops::FromResidual<Result<convert::Infallible, E>> for Resultish<T, F>
+where
+    F: From<E>,
+{
+    fn from_residual(residual: Result<convert::Infallible, E>) -> Self {
+        match residual {
+            Result::Err(err) => Err(From::from(err)),
+        }
+    }
+}
+
+impl<T, E> From<Resultish<T, E>> for (Option<T>, Option<E>) {
+    /// Converts to a tuple of the success and error values. See [`Resultish::tuple`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(<(Option<_>, Option<_>)>::from(x), (Some(3), None));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// let y: (Option<i32>, Option<&str>) = x.into();
+    /// assert_eq!(y, (None, Some("Some error message")));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// let y: (Option<i32>, Option<&str>) = x.into();
+    /// assert_eq!(y, (Some(3), Some("Some error message")));
+    /// ```
+    fn from(value: Resultish<T, E>) -> Self {
+        value.tuple()
+    }
+}
+
+/// [`Ok`] and [`Err`] are equal to [`Result::Ok`] and [`Result::Err`] with equal values. [`Both`]
+/// is never equal to a [`Result`].
+impl<T, E> PartialEq<Result<T, E>> for Resultish<T, E>
+where
+    T: PartialEq,
+    E: PartialEq,
+{
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert!(x == Result::Ok(3));
+    /// assert!(x != Result::Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert!(x != Result::Ok(3));
+    /// assert!(x == Result::Err("Some error message"));
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert!(x != Result::Ok(3));
+    /// assert!(x != Result::Err("Some error message"));
+    /// ```
+    fn eq(&self, other: &Result<T, E>) -> bool {
+        match (self, other) {
+            (Ok(ok), Result::Ok(other_ok)) => ok == other_ok,
+            (Err(err), Result::Err(other_err)) => err == other_err,
+            _ => false,
+        }
+    }
+}
+
+/// [`Result::Ok`] and [`Result::Err`] are equal to [`Ok`] and [`Err`] with equal values. A
+/// [`Result`] is never equal to [`Both`].
+impl<T, E> PartialEq<Resultish<T, E>> for Result<T, E>
+where
+    T: PartialEq,
+    E: PartialEq,
+{
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Result<i32, &str> = Result::Ok(3);
+    /// assert!(x == Ok(3));
+    /// assert!(x != Both(3, "Some error message"));
+    ///
+    /// let x: Result<i32, &str> = Result::Err("Some error message");
+    /// assert!(x == Err("Some error message"));
+    /// assert!(x != Both(3, "Some error message"));
+    /// ```
+    fn eq(&self, other: &Resultish<T, E>) -> bool {
+        other == self
+    }
+}
+
+impl<T, E> TryFrom<(Option<T>, Option<E>)> for Resultish<T, E> {
+    type Error = FromTupleError;
+
+    /// Converts from a success and error value, each of which may be absent, to a `Resultish`.
+    ///
+    /// See [`Resultish::from_tuple`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromTupleError`] if both values are absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::FromTupleError;
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x = Resultish::<i32, &str>::try_from((Some(3), None));
+    /// assert_eq!(x, Result::Ok(Ok(3)));
+    ///
+    /// let x = Resultish::<i32, &str>::try_from((None, Some("Some error message")));
+    /// assert_eq!(x, Result::Ok(Err("Some error message")));
+    ///
+    /// let x = Resultish::<i32, &str>::try_from((Some(3), Some("Some error message")));
+    /// assert_eq!(x, Result::Ok(Both(3, "Some error message")));
+    ///
+    /// let x = Resultish::<i32, &str>::try_from((None, None));
+    /// assert_eq!(x, Result::Err(FromTupleError));
+    /// ```
+    fn try_from((ok, err): (Option<T>, Option<E>)) -> Result<Self, FromTupleError> {
+        Resultish::from_tuple(ok, err).ok_or(FromTupleError)
+    }
+}
+
+/// The error type returned when converting a tuple where both the success and error values are
+/// absent into a [`Resultish`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct FromTupleError;
+
+impl fmt::Display for FromTupleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "both the success and error values are absent")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for FromTupleError {}
+
+/// The number of results of each variant, returned by [`Resultish::tally`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub struct Tally {
+    /// The number of [`Ok`] results
+    pub ok_only: usize,
+    /// The number of [`Err`] results
+    pub err_only: usize,
+    /// The number of [`Both`] results
+    pub both: usize,
+    /// The number of results containing a success value, i.e. [`Ok`] or [`Both`]
+    pub any_ok: usize,
+    /// The number of results containing an error value, i.e. [`Err`] or [`Both`]
+    pub any_err: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, E, C> FromIterator<Resultish<T, E>> for Resultish<C, Vec<E>>
+where
+    C: FromIterator<T>,
+{
+    /// Collects all success values into `C`, and all error values into a [`Vec`].
+    ///
+    /// The result is [`Ok`] if there are no error values, [`Err`] if there are error values but
+    /// no success values, and [`Both`] otherwise. An empty iterator results in [`Ok`] with an empty
+    /// collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// let x: Resultish<Vec<i32>, Vec<&str>> = x.into_iter().collect();
+    /// assert_eq!(x, Both(vec![1, 2, 3], vec!["First", "Second"]));
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Ok(2)];
+    /// let x: Resultish<Vec<i32>, Vec<&str>> = x.into_iter().collect();
+    /// assert_eq!(x, Ok(vec![1, 2]));
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Err("First"), Err("Second")];
+    /// let x: Resultish<Vec<i32>, Vec<&str>> = x.into_iter().collect();
+    /// assert_eq!(x, Err(vec!["First", "Second"]));
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![];
+    /// let x: Resultish<Vec<i32>, Vec<&str>> = x.into_iter().collect();
+    /// assert_eq!(x, Ok(vec![]));
+    /// ```
+    ///
+    /// All error values are collected, even if `C` stops consuming the success values early:
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Vec<Resultish<Option<i32>, &str>> = vec![Ok(None), Err("First"), Ok(Some(1))];
+    /// let x: Resultish<Option<Vec<i32>>, Vec<&str>> = x.into_iter().collect();
+    /// assert_eq!(x, Both(None, vec!["First"]));
+    /// ```
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Resultish<T, E>>,
+    {
+        let mut has_ok = false;
+        let mut errs = Vec::new();
+        let mut iter = iter.into_iter().filter_map(|item| match item {
+            Ok(ok) => {
+                has_ok = true;
+                Some(ok)
+            }
+            Err(err) => {
+                errs.push(err);
+                None
+            }
+            Both(ok, err) => {
+                has_ok = true;
+                errs.push(err);
+                Some(ok)
+            }
+        });
+        let oks = iter.by_ref().collect();
+        // `C` may stop early, e.g. when collecting into an `Option`, so drain the rest to collect
+        // every error.
+        iter.for_each(drop);
+
+        match (has_ok, errs.is_empty()) {
+            (_, true) => Ok(oks),
+            (false, false) => Err(errs),
+            (true, false) => Both(oks, errs),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, E> Extend<Resultish<T, E>> for Resultish<Vec<T>, Vec<E>> {
+    /// Pushes the success and error values of each item onto the success and error vectors. See
+    /// [`push`](Resultish::push).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let mut x: Resultish<Vec<i32>, Vec<&str>> = Ok(vec![]);
+    /// x.extend(vec![Ok(1), Ok(2)]);
+    /// assert_eq!(x, Ok(vec![1, 2]));
+    ///
+    /// x.extend(vec![Both(3, "First"), Err("Second"), Ok(4)]);
+    /// assert_eq!(x, Both(vec![1, 2, 3, 4], vec!["First", "Second"]));
+    /// ```
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Resultish<T, E>>,
+    {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, U, E> iter::Sum<Resultish<U, E>> for Resultish<T, E>
+where
+    T: iter::Sum<U>,
+{
+    /// Sums all success values, keeping the first error value, if any.
+    ///
+    /// Unlike summing [`Result`]s, this doesn't short-circuit: success values after an error value
+    /// are still summed, and later error values are discarded. The result is [`Ok`] if there are no
+    /// error values, [`Err`] if there are error values but no success values, and [`Both`]
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// assert_eq!(x.into_iter().sum::<Resultish<i32, &str>>(), Both(6, "First"));
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Ok(2)];
+    /// assert_eq!(x.into_iter().sum::<Resultish<i32, &str>>(), Ok(3));
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Err("First"), Err("Second")];
+    /// assert_eq!(x.into_iter().sum::<Resultish<i32, &str>>(), Err("First"));
+    /// ```
+    ///
+    /// Error values are found even if summing the success values stops early, as with [`Option`]:
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Vec<Resultish<Option<i32>, &str>> = vec![Ok(None), Err("First"), Ok(Some(1))];
+    /// assert_eq!(x.into_iter().sum::<Resultish<Option<i32>, &str>>(), Both(None, "First"));
+    /// ```
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Resultish<U, E>>,
+    {
+        accumulate(iter, |oks| oks.sum())
+    }
+}
+
+impl<T, U, E> iter::Product<Resultish<U, E>> for Resultish<T, E>
+where
+    T: iter::Product<U>,
+{
+    /// Multiplies all success values, keeping the first error value, if any.
+    ///
+    /// The error values are handled the same way as when summing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(2), Both(3, "First"), Err("Second"), Ok(4)];
+    /// assert_eq!(x.into_iter().product::<Resultish<i32, &str>>(), Both(24, "First"));
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(2), Ok(3)];
+    /// assert_eq!(x.into_iter().product::<Resultish<i32, &str>>(), Ok(6));
+    /// ```
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Resultish<U, E>>,
+    {
+        accumulate(iter, |oks| oks.product())
+    }
+}
+
+impl<T, E> IntoIterator for Resultish<T, E> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the possibly contained success value.
+    ///
+    /// The iterator yields one value if the result contains a success value, otherwise none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    ///
+    /// let x: Resultish<i32, &str> = Ok(3);
+    /// assert_eq!(x.into_iter().collect::<Vec<_>>(), vec![3]);
+    ///
+    /// let x: Resultish<i32, &str> = Err("Some error message");
+    /// assert_eq!(x.into_iter().collect::<Vec<_>>(), vec![]);
+    ///
+    /// let x: Resultish<i32, &str> = Both(3, "Some error message");
+    /// assert_eq!(x.into_iter().len(), 1);
+    /// assert_eq!(x.into_iter().collect::<Vec<_>>(), vec![3]);
+    /// ```
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            inner: self.lenient_ok(),
+        }
+    }
+}
+
+impl<'a, T, E> IntoIterator for &'a Resultish<T, E> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, E> IntoIterator for &'a mut Resultish<T, E> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over a reference to the success value of a [`Resultish`].
+///
+/// The iterator yields one value if the result contains a success value, otherwise none.
+///
+/// Created by [`Resultish::iter`].
+#[derive(Debug)]
+pub struct Iter<'a, T> {
+    inner: Option<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.inner.take()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { inner: self.inner }
+    }
+}
+
+/// An iterator over a mutable reference to the success value of a [`Resultish`].
+///
+/// The iterator yields one value if the result contains a success value, otherwise none.
+///
+/// Created by [`Resultish::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    inner: Option<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.inner.take()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// An iterator over the success value of a [`Resultish`].
+///
+/// The iterator yields one value if the result contains a success value, otherwise none.
+///
+/// This struct is created by the [`into_iter`](IntoIterator::into_iter) method on [`Resultish`]
+/// (provided by the [`IntoIterator`] trait).
+#[derive(Clone, Debug)]
+pub struct IntoIter<T> {
+    inner: Option<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.take()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// An iterator over the success and error values of a [`Resultish`].
+///
+/// The iterator yields a [`Side::Ok`] if the result contains a success value, followed by a
+/// [`Side::Err`] if the result contains an error value.
+///
+/// Created by [`Resultish::into_iter_both`].
+#[derive(Clone, Debug)]
+pub struct IntoIterBoth<T, E> {
+    ok: Option<T>,
+    err: Option<E>,
+}
+
+impl<T, E> Iterator for IntoIterBoth<T, E> {
+    type Item = Side<T, E>;
+
+    fn next(&mut self) -> Option<Side<T, E>> {
+        match self.ok.take() {
+            Some(ok) => Some(Side::Ok(ok)),
+            None => self.err.take().map(Side::Err),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.ok.is_some() as usize + self.err.is_some() as usize;
+        (n, Some(n))
+    }
+}
+
+impl<T, E> DoubleEndedIterator for IntoIterBoth<T, E> {
+    fn next_back(&mut self) -> Option<Side<T, E>> {
+        match self.err.take() {
+            Some(err) => Some(Side::Err(err)),
+            None => self.ok.take().map(Side::Ok),
+        }
+    }
+}
+
+impl<T, E> ExactSizeIterator for IntoIterBoth<T, E> {}
+
+impl<T, E> FusedIterator for IntoIterBoth<T, E> {}
+
+/// An iterator over a reference to the error value of a [`Resultish`].
+///
+/// The iterator yields one value if the result contains an error value, otherwise none.
+///
+/// Created by [`Resultish::iter_err`].
+#[derive(Debug)]
+pub struct IterErr<'a, E> {
+    inner: Option<&'a E>,
+}
+
+impl<'a, E> Iterator for IterErr<'a, E> {
+    type Item = &'a E;
+
+    fn next(&mut self) -> Option<&'a E> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<'a, E> DoubleEndedIterator for IterErr<'a, E> {
+    fn next_back(&mut self) -> Option<&'a E> {
+        self.inner.take()
+    }
+}
+
+impl<E> ExactSizeIterator for IterErr<'_, E> {}
+
+impl<E> FusedIterator for IterErr<'_, E> {}
+
+impl<E> Clone for IterErr<'_, E> {
+    fn clone(&self) -> Self {
+        IterErr { inner: self.inner }
+    }
+}
+
+/// An iterator over a mutable reference to the error value of a [`Resultish`].
+///
+/// The iterator yields one value if the result contains an error value, otherwise none.
+///
+/// Created by [`Resultish::iter_err_mut`].
+#[derive(Debug)]
+pub struct IterErrMut<'a, E> {
+    inner: Option<&'a mut E>,
+}
+
+impl<'a, E> Iterator for IterErrMut<'a, E> {
+    type Item = &'a mut E;
+
+    fn next(&mut self) -> Option<&'a mut E> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, Some(n))
+    }
+}
+
+impl<'a, E> DoubleEndedIterator for IterErrMut<'a, E> {
+    fn next_back(&mut self) -> Option<&'a mut E> {
+        self.inner.take()
+    }
+}
+
+impl<E> ExactSizeIterator for IterErrMut<'_, E> {}
+
+impl<E> FusedIterator for IterErrMut<'_, E> {}
+
+/// Extension methods for iterators over [`Resultish`] values.
+///
+/// This is implemented for every iterator with an item type of `Resultish<T, E>`.
+pub trait ResultishIterExt<T, E>: Iterator<Item = Resultish<T, E>> + Sized {
+    /// Returns an iterator over the success values, skipping items without one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use resultish::ResultishIterExt;
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// let oks: Vec<i32> = x.into_iter().successes().collect();
+    /// assert_eq!(oks, [1, 2, 3]);
+    /// ```
+    fn successes(self) -> Successes<Self> {
+        Successes { inner: self }
+    }
+
+    /// Returns an iterator over the error values, skipping items without one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use resultish::ResultishIterExt;
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// let errs: Vec<&str> = x.into_iter().errors().collect();
+    /// assert_eq!(errs, ["First", "Second"]);
+    /// ```
+    fn errors(self) -> Errors<Self> {
+        Errors { inner: self }
+    }
+
+    /// Consumes the iterator, collecting all success values and all error values into separate
+    /// vectors. The values of [`Both`] are placed into both vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use resultish::ResultishIterExt;
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// let (oks, errs) = x.into_iter().partition_resultish();
+    /// assert_eq!(oks, [1, 2, 3]);
+    /// assert_eq!(errs, ["First", "Second"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn partition_resultish(self) -> (Vec<T>, Vec<E>) {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for item in self {
+            match item {
+                Ok(ok) => oks.push(ok),
+                Err(err) => errs.push(err),
+                Both(ok, err) => {
+                    oks.push(ok);
+                    errs.push(err);
+                }
+            }
+        }
+        (oks, errs)
+    }
+
+    /// Consumes the iterator, collecting all success values into `C`, and keeping the first error
+    /// value, if any.
+    ///
+    /// Later error values are discarded, so unlike collecting into `Resultish<C, Vec<E>>`, the
+    /// error values don't need to be stored. The result is [`Ok`] if there are no error values,
+    /// [`Err`] if there are error values but no success values, and [`Both`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use resultish::ResultishIterExt;
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// assert_eq!(x.into_iter().collect_both::<Vec<_>>(), Both(vec![1, 2, 3], "First"));
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Ok(2)];
+    /// assert_eq!(x.into_iter().collect_both::<Vec<_>>(), Ok(vec![1, 2]));
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Err("First"), Err("Second")];
+    /// assert_eq!(x.into_iter().collect_both::<Vec<_>>(), Err("First"));
+    ///
+    /// // Error values are found even if `C` stops consuming the success values early.
+    /// let x: Vec<Resultish<Option<i32>, &str>> = vec![Ok(None), Err("First"), Ok(Some(1))];
+    /// assert_eq!(x.into_iter().collect_both::<Option<Vec<_>>>(), Both(None, "First"));
+    /// ```
+    fn collect_both<C>(self) -> Resultish<C, E>
+    where
+        C: FromIterator<T>,
+    {
+        accumulate(self, |oks| oks.collect())
+    }
+
+    /// Consumes the iterator, counting the items that contain a success value, including [`Both`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use resultish::ResultishIterExt;
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// assert_eq!(x.into_iter().count_ok(), 3);
+    /// ```
+    fn count_ok(self) -> usize {
+        self.filter(Resultish::has_ok).count()
+    }
+
+    /// Consumes the iterator, counting the items that contain an error value, including [`Both`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use resultish::ResultishIterExt;
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// assert_eq!(x.into_iter().count_err(), 2);
+    /// ```
+    fn count_err(self) -> usize {
+        self.filter(Resultish::has_err).count()
+    }
+
+    /// Consumes the iterator, counting the items of each variant, as `(ok, err, both)`.
+    ///
+    /// Unlike [`count_ok`](Self::count_ok) and [`count_err`](Self::count_err), each item is
+    /// counted exactly once, so [`Both`] is only counted in the last count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use resultish::Resultish::{self, Both, Err, Ok};
+    /// use resultish::ResultishIterExt;
+    ///
+    /// let x: Vec<Resultish<i32, &str>> = vec![Ok(1), Both(2, "First"), Err("Second"), Ok(3)];
+    /// assert_eq!(x.into_iter().count_variants(), (2, 1, 1));
+    /// ```
+    fn count_variants(self) -> (usize, usize, usize) {
+        let mut counts = (0, 0, 0);
+        for item in self {
+            match item {
+                Ok(_) => counts.0 += 1,
+                Err(_) => counts.1 += 1,
+                Both(_, _) => counts.2 += 1,
+            }
+        }
+        counts
+    }
+}
+
+impl<I, T, E> ResultishIterExt<T, E> for I where I: Iterator<Item = Resultish<T, E>> {}
+
+/// An iterator over the success values of an iterator of [`Resultish`] values.
+///
+/// Created by [`ResultishIterExt::successes`].
+#[derive(Clone, Debug)]
+pub struct Successes<I> {
+    inner: I,
+}
+
+impl<I, T, E> Iterator for Successes<I>
+where
+    I: Iterator<Item = Resultish<T, E>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.find_map(Resultish::ok)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<I, T, E> DoubleEndedIterator for Successes<I>
+where
+    I: DoubleEndedIterator<Item = Resultish<T, E>>,
+{
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.by_ref().rev().find_map(Resultish::ok)
+    }
+}
+
+impl<I, T, E> FusedIterator for Successes<I> where I: FusedIterator<Item = Resultish<T, E>> {}
+
+/// An iterator over the error values of an iterator of [`Resultish`] values.
+///
+/// Created by [`ResultishIterExt::errors`].
+#[derive(Clone, Debug)]
+pub struct Errors<I> {
+    inner: I,
+}
+
+impl<I, T, E> Iterator for Errors<I>
+where
+    I: Iterator<Item = Resultish<T, E>>,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        self.inner.find_map(Resultish::err)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<I, T, E> DoubleEndedIterator for Errors<I>
+where
+    I: DoubleEndedIterator<Item = Resultish<T, E>>,
+{
+    fn next_back(&mut self) -> Option<E> {
+        self.inner.by_ref().rev().find_map(Resultish::err)
+    }
+}
+
+impl<I, T, E> FusedIterator for Errors<I> where I: FusedIterator<Item = Resultish<T, E>> {}
+
+// Separate function to reduce the code size of the `unwrap` family of methods.
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn unwrap_failed(msg: &str, value: &dyn fmt::Debug) -> ! {
+    panic!("{msg}: {value:?}")
+}
+
+// Calls `f` with an iterator over the success values of `iter`, keeping the first error value.
+// Shared by the `Sum` and `Product` impls, and `ResultishIterExt::collect_both`.
+fn accumulate<I, T, E, R, F>(iter: I, f: F) -> Resultish<R, E>
+where
+    I: Iterator<Item = Resultish<T, E>>,
+    F: FnOnce(&mut dyn Iterator<Item = T>) -> R,
+{
+    let mut has_ok = false;
+    let mut first_err = None;
+    let mut oks = iter.filter_map(|item| {
+        let (ok, err) = item.tuple();
+        has_ok |= ok.is_some();
+        if first_err.is_none() {
+            first_err = err;
+        }
+        ok
+    });
+    let acc = f(&mut oks);
+    // `f` may stop early, e.g. when summing `Option`s, so drain the rest to find every error.
+    oks.for_each(drop);
+
+    match (has_ok, first_err) {
+        (_, None) => Ok(acc),
+        (false, Some(err)) => Err(err),
+        (true, Some(err)) => Both(acc, err),
+    }
+}
+
+// Moves the value out of `dest`, and replaces it with the value returned by `f`. This allows
+// changing the variant in place, e.g. from `Err(err)` to `Both(ok, err)`, which isn't possible with
+// `mem::replace` as `Resultish` has no empty state to leave behind. Leaving `Ok(ok)` or `Err(err)`
+// behind instead would require owning the very value being moved, and `mem::take` would require
+// `T: Default` and `E: Default`.
+//
+// If `f` panics, the process is aborted, as `dest` would otherwise be dropped while its value has
+// been moved out.
+fn replace_with<T, E, R, F>(dest: &mut Resultish<T, E>, f: F) -> R
+where
+    F: FnOnce(Resultish<T, E>) -> (Resultish<T, E>, R),
+{
+    struct AbortOnUnwind;
+
+    impl Drop for AbortOnUnwind {
+        fn drop(&mut self) {
+            // Panicking while already unwinding aborts the process.
+            panic!("panicked while replacing a `Resultish` in place");
+        }
+    }
+
+    let guard = AbortOnUnwind;
+    // SAFETY: `dest` is valid for reads and writes, as it's a mutable reference. The value read
+    // out of `dest` is never dropped in place: `dest` is overwritten before being used again, and
+    // if `f` unwinds in between, `guard` aborts the process before `dest` can be dropped.
+    let ret = unsafe {
+        let (value, ret) = f(ptr::read(dest));
+        ptr::write(dest, value);
+        ret
+    };
+    mem::forget(guard);
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Resultish::{self, Both, Err, Ok};
+
+    // The `debug_assert!` in `debug_assert_err` and `debug_assert_ok` follows the profile the
+    // library is built with, so whether they panic depends on the build. Unit tests are built
+    // with the library, so they can check both behaviours with `cfg(debug_assertions)`.
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn debug_assert_err_panics_on_ok() {
+        let x: Resultish<i32, &str> = Ok(3);
+        x.debug_assert_err();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn debug_assert_ok_panics_on_err() {
+        let x: Resultish<i32, &str> = Err("Some error message");
+        x.debug_assert_ok();
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn debug_assert_does_nothing_without_debug_assertions() {
+        let x: Resultish<i32, &str> = Ok(3);
+        assert_eq!(x.debug_assert_err(), &Ok(3));
+
+        let x: Resultish<i32, &str> = Err("Some error message");
+        assert_eq!(x.debug_assert_ok(), &Err("Some error message"));
+    }
+
+    // Only uses the core API, so it also runs under `cargo test --no-default-features`, where the
+    // crate itself is built as `no_std`. Run that alongside the default `cargo test`, as a default
+    // build doesn't catch code that only compiles with `std` or `alloc`.
+    #[test]
+    fn core_api_without_std() {
+        let x: Resultish<i32, &str> = Both(3, "Some error message");
+        assert!(x.is_both() && x.has_ok() && x.has_err());
+        assert_eq!(x.map(|ok| ok * 2), Both(6, "Some error message"));
+        assert_eq!(x.and_then(|ok| Ok(ok + 1)), Both(4, "Some error message"));
+        assert_eq!(x.lenient(), Result::Ok(3));
+        assert_eq!(x.strict(), Result::Err("Some error message"));
+        assert_eq!(x.tuple(), (Some(3), Some("Some error message")));
+        assert_eq!(x.iter().next(), Some(&3));
+        assert_eq!(Resultish::from_tuple(Some(3), None::<&str>), Some(Ok(3)));
+
+        let x: Resultish<i32, &str> = Err("Some error message");
+        assert_eq!(x.unwrap_or(0), 0);
+        assert_eq!(x.zip(Ok(4)), Err("Some error message"));
+
+        let x = [Ok(1), Both(2, "First"), Err("Second")];
+        let sum: Resultish<i32, &str> = x.into_iter().sum();
+        assert_eq!(sum, Both(3, "First"));
+    }
 }